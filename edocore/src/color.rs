@@ -0,0 +1,407 @@
+//=============================================================================
+// color.rs
+// Colors in linear, sRGB, HSV and Oklab spaces
+//
+// Lighting, blending and interpolation belong in linear space. sRGB is what
+// images, hex codes and color pickers use, so convert at those boundaries.
+//=============================================================================
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use crate::math::clamp01;
+use crate::math::float::FloatMath;
+use crate::math::vector::Vector4;
+
+/// A color with linear channels, straight (not premultiplied) alpha unless stated otherwise
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct LinearRgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+/// A color with sRGB encoded channels in [0, 1] and linear alpha
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Srgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+/// A color as hue in degrees [0, 360), saturation and value in [0, 1],
+/// over sRGB encoded channels like color pickers use
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hsva {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+/// A color in the perceptual Oklab space: lightness, green-red and blue-yellow.
+/// Equal distances look about equally different, which makes for even gradients
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+/// Why a hex color could not be parsed
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseHexError {
+    /// Not 3, 4, 6 or 8 digits
+    InvalidLength,
+    /// A character that is not a hexadecimal digit
+    InvalidDigit,
+}
+
+/// Colors placed along [0, 1] and blended in linear space in between
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// Position and color of every stop, sorted by position
+    stops: Vec<(f32, LinearRgba)>,
+}
+
+/// Converts an sRGB encoded channel into linear
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        FloatMath::powf((value + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear channel into sRGB encoded
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * FloatMath::powf(value, 1.0 / 2.4) - 0.055
+    }
+}
+
+impl LinearRgba {
+    pub const BLACK: LinearRgba = LinearRgba::new(0.0, 0.0, 0.0, 1.0);
+    pub const TRANSPARENT: LinearRgba = LinearRgba::new(0.0, 0.0, 0.0, 0.0);
+    pub const WHITE: LinearRgba = LinearRgba::new(1.0, 1.0, 1.0, 1.0);
+
+    /// Composites "src" over "dst", both with straight alpha
+    pub fn blend_over(src: LinearRgba, dst: LinearRgba) -> LinearRgba {
+        let src = src.premultiplied();
+        let dst = dst.premultiplied();
+        let rest = 1.0 - src.a;
+        LinearRgba::new(src.r + dst.r * rest, src.g + dst.g * rest, src.b + dst.b * rest, src.a + dst.a * rest)
+            .unpremultiplied()
+    }
+
+    /// Interpolates linearly between two colors
+    pub fn lerp(a: LinearRgba, b: LinearRgba, t: f32) -> LinearRgba {
+        let t = clamp01(t);
+        LinearRgba::new(
+            a.r + (b.r - a.r) * t,
+            a.g + (b.g - a.g) * t,
+            a.b + (b.b - a.b) * t,
+            a.a + (b.a - a.a) * t,
+        )
+    }
+
+    /// Creates a new color
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> LinearRgba {
+        LinearRgba { r, g, b, a }
+    }
+
+    /// Returns the color with its channels multiplied by alpha
+    pub fn premultiplied(self) -> LinearRgba {
+        LinearRgba::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Creates an opaque color
+    pub const fn rgb(r: f32, g: f32, b: f32) -> LinearRgba {
+        LinearRgba::new(r, g, b, 1.0)
+    }
+
+    /// Returns the color with its channels divided by alpha, undoing `premultiplied`.
+    /// Fully transparent colors become transparent black
+    pub fn unpremultiplied(self) -> LinearRgba {
+        if self.a == 0.0 {
+            return LinearRgba::TRANSPARENT;
+        }
+        LinearRgba::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+}
+
+impl Srgba {
+    pub const BLACK: Srgba = Srgba::new(0.0, 0.0, 0.0, 1.0);
+    pub const TRANSPARENT: Srgba = Srgba::new(0.0, 0.0, 0.0, 0.0);
+    pub const WHITE: Srgba = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+    /// Creates a color from a u32 laid out as 0xBBGGRRAA
+    pub fn from_bgra8(value: u32) -> Srgba {
+        let [b, g, r, a] = value.to_be_bytes();
+        Srgba::from_bytes([r, g, b, a])
+    }
+
+    /// Creates a color from 8 bit channels in the order r, g, b, a
+    pub fn from_bytes(bytes: [u8; 4]) -> Srgba {
+        let [r, g, b, a] = bytes.map(|c| c as f32 / 255.0);
+        Srgba::new(r, g, b, a)
+    }
+
+    /// Parses "#RGB", "#RGBA", "#RRGGBB" or "#RRGGBBAA", the "#" being optional
+    pub fn from_hex(hex: &str) -> Result<Srgba, ParseHexError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let mut values = [0u8; 8];
+        if !matches!(digits.len(), 3 | 4 | 6 | 8) {
+            return Err(ParseHexError::InvalidLength);
+        }
+        for (value, c) in values.iter_mut().zip(digits.chars()) {
+            *value = c.to_digit(16).ok_or(ParseHexError::InvalidDigit)? as u8;
+        }
+
+        let channels = match digits.len() {
+            3 => [values[0] * 17, values[1] * 17, values[2] * 17, 255],
+            4 => [values[0] * 17, values[1] * 17, values[2] * 17, values[3] * 17],
+            len => {
+                let byte = |i: usize| if i < len / 2 { values[2 * i] * 16 + values[2 * i + 1] } else { 255 };
+                [byte(0), byte(1), byte(2), byte(3)]
+            }
+        };
+        Ok(Srgba::from_bytes(channels))
+    }
+
+    /// Creates a color from a u32 laid out as 0xRRGGBBAA
+    pub fn from_rgba8(value: u32) -> Srgba {
+        Srgba::from_bytes(value.to_be_bytes())
+    }
+
+    /// Interpolates between two colors in linear space, which keeps
+    /// the midpoint of two saturated colors from turning dark
+    pub fn lerp(a: Srgba, b: Srgba, t: f32) -> Srgba {
+        LinearRgba::lerp(a.into(), b.into(), t).into()
+    }
+
+    /// Creates a new color
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Srgba {
+        Srgba { r, g, b, a }
+    }
+
+    /// Creates an opaque color
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Srgba {
+        Srgba::new(r, g, b, 1.0)
+    }
+
+    /// Packs the color into a u32 laid out as 0xBBGGRRAA
+    pub fn to_bgra8(self) -> u32 {
+        let [r, g, b, a] = self.to_bytes();
+        u32::from_be_bytes([b, g, r, a])
+    }
+
+    /// Returns the channels rounded to 8 bits, in the order r, g, b, a
+    pub fn to_bytes(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| FloatMath::round(clamp01(c) * 255.0) as u8)
+    }
+
+    /// Returns the color as "#RRGGBBAA"
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_bytes();
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+
+    /// Packs the color into a u32 laid out as 0xRRGGBBAA
+    pub fn to_rgba8(self) -> u32 {
+        u32::from_be_bytes(self.to_bytes())
+    }
+}
+
+impl Hsva {
+    /// Creates a new color
+    pub const fn new(h: f32, s: f32, v: f32, a: f32) -> Hsva {
+        Hsva { h, s, v, a }
+    }
+}
+
+impl Oklab {
+    /// Interpolates linearly between two colors, perceptually even
+    pub fn lerp(a: Oklab, b: Oklab, t: f32) -> Oklab {
+        let t = clamp01(t);
+        Oklab::new(
+            a.l + (b.l - a.l) * t,
+            a.a + (b.a - a.a) * t,
+            a.b + (b.b - a.b) * t,
+            a.alpha + (b.alpha - a.alpha) * t,
+        )
+    }
+
+    /// Creates a new color
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Oklab {
+        Oklab { l, a, b, alpha }
+    }
+}
+
+impl Gradient {
+    /// Adds a stop at "position", after any existing stop at the same position
+    pub fn add_stop(&mut self, position: f32, color: LinearRgba) {
+        let index = self.stops.partition_point(|&(p, _)| p <= position);
+        self.stops.insert(index, (position, color));
+    }
+
+    /// Returns the color at "t", the color of the nearest stop outside of the stops.
+    /// Transparent when there are no stops
+    pub fn evaluate(&self, t: f32) -> LinearRgba {
+        let index = self.stops.partition_point(|&(p, _)| p <= t);
+        if index == 0 {
+            return self.stops.first().map_or(LinearRgba::TRANSPARENT, |&(_, color)| color);
+        }
+        if index == self.stops.len() {
+            return self.stops[index - 1].1;
+        }
+
+        let (start, from) = self.stops[index - 1];
+        let (end, to) = self.stops[index];
+        LinearRgba::lerp(from, to, (t - start) / (end - start))
+    }
+
+    /// Creates a gradient from stops in any order
+    pub fn new(stops: &[(f32, LinearRgba)]) -> Gradient {
+        let mut gradient = Gradient::default();
+        for &(position, color) in stops {
+            gradient.add_stop(position, color);
+        }
+        gradient
+    }
+
+    /// Returns the stops, sorted by position
+    pub fn stops(&self) -> &[(f32, LinearRgba)] {
+        &self.stops
+    }
+}
+
+impl From<Srgba> for LinearRgba {
+    fn from(color: Srgba) -> Self {
+        LinearRgba::new(srgb_to_linear(color.r), srgb_to_linear(color.g), srgb_to_linear(color.b), color.a)
+    }
+}
+
+impl From<LinearRgba> for Srgba {
+    fn from(color: LinearRgba) -> Self {
+        Srgba::new(linear_to_srgb(color.r), linear_to_srgb(color.g), linear_to_srgb(color.b), color.a)
+    }
+}
+
+impl From<Srgba> for Hsva {
+    fn from(color: Srgba) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == color.r {
+            60.0 * FloatMath::rem_euclid((color.g - color.b) / delta, 6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Hsva::new(h, s, max, color.a)
+    }
+}
+
+impl From<Hsva> for Srgba {
+    fn from(color: Hsva) -> Self {
+        let h = FloatMath::rem_euclid(color.h, 360.0) / 60.0;
+        let chroma = color.v * color.s;
+        let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+        let m = color.v - chroma;
+
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Srgba::new(r + m, g + m, b + m, color.a)
+    }
+}
+
+impl From<LinearRgba> for Oklab {
+    fn from(color: LinearRgba) -> Self {
+        let l = FloatMath::cbrt(0.412_221_47 * color.r + 0.536_332_55 * color.g + 0.051_445_99 * color.b);
+        let m = FloatMath::cbrt(0.211_903_5 * color.r + 0.680_699_5 * color.g + 0.107_396_96 * color.b);
+        let s = FloatMath::cbrt(0.088_302_46 * color.r + 0.281_718_85 * color.g + 0.629_978_7 * color.b);
+
+        Oklab::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            color.a,
+        )
+    }
+}
+
+impl From<Oklab> for LinearRgba {
+    fn from(color: Oklab) -> Self {
+        let l = color.l + 0.396_337_78 * color.a + 0.215_803_76 * color.b;
+        let m = color.l - 0.105_561_346 * color.a - 0.063_854_17 * color.b;
+        let s = color.l - 0.089_484_18 * color.a - 1.291_485_5 * color.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        LinearRgba::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            color.alpha,
+        )
+    }
+}
+
+impl From<LinearRgba> for Vector4 {
+    fn from(color: LinearRgba) -> Self {
+        Vector4::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Vector4> for LinearRgba {
+    fn from(vector: Vector4) -> Self {
+        LinearRgba::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl FromStr for Srgba {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Srgba::from_hex(s)
+    }
+}
+
+impl Display for ParseHexError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            ParseHexError::InvalidLength => write!(f, "a hex color needs 3, 4, 6 or 8 digits"),
+            ParseHexError::InvalidDigit => write!(f, "invalid hexadecimal digit"),
+        }
+    }
+}
+
+impl core::error::Error for ParseHexError {}
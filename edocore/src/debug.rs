@@ -5,7 +5,7 @@
 // Created by Victor on 2019/10/24
 //=============================================================================
 
-use log::{debug, error, info, trace, warn};
+use log::info;
 
 pub fn log(s: &str) {
     //println!("{}", s); // TODO: We should log to a file, etc
@@ -1,2 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// The dylib needs a panic handler and an allocator. Without "std" they are
+// linked from std where the target has it, but std stays unnameable so the code
+// can still only use core and alloc. Bare metal targets only build the rlib
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std as _;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("edocore needs either the \"std\" or the \"libm\" feature for its float functions");
+
+pub mod color;
+#[cfg(feature = "debug")]
 pub mod debug;
 pub mod math;
@@ -0,0 +1,204 @@
+//=============================================================================
+// angle.rs
+// Angles that carry their unit in their type
+//
+// Functions taking an angle accept `impl Into<Radians<T>>`, so either unit can
+// be passed and the conversion happens once, at the call.
+//=============================================================================
+
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::math::scalar::{Float, Real, Scalar};
+
+/// An angle in radians, a full turn being 2π
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Radians<T = f32>(pub T);
+
+/// An angle in degrees, a full turn being 360
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Degrees<T = f32>(pub T);
+
+impl<T: Real> Radians<T> {
+    /// Returns the cosine of the angle
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    /// Returns the sine of the angle
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    /// Returns the sine and the cosine of the angle
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    /// Returns the tangent of the angle
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    fn half_turn() -> T {
+        T::PI
+    }
+}
+
+impl<T: Real> Degrees<T> {
+    /// Returns the cosine of the angle
+    pub fn cos(self) -> T {
+        Radians::from(self).cos()
+    }
+
+    /// Returns the sine of the angle
+    pub fn sin(self) -> T {
+        Radians::from(self).sin()
+    }
+
+    /// Returns the sine and the cosine of the angle
+    pub fn sin_cos(self) -> (T, T) {
+        Radians::from(self).sin_cos()
+    }
+
+    /// Returns the tangent of the angle
+    pub fn tan(self) -> T {
+        Radians::from(self).tan()
+    }
+
+    fn half_turn() -> T {
+        T::from_f32(180.0)
+    }
+}
+
+macro_rules! impl_angle {
+    ($($angle:ident: $half:literal, $full:literal);*) => {$(
+        impl<T: Real> $angle<T> {
+            /// Returns the signed angle that turns "from" into "to" the short way round,
+            #[doc = concat!("in (-", $half, ", ", $half, "]")]
+            pub fn shortest_delta(from: Self, to: Self) -> Self {
+                (to - from).wrap_pi()
+            }
+
+            /// Returns the same direction as an angle within half a turn of zero,
+            #[doc = concat!("in (-", $half, ", ", $half, "]")]
+            pub fn wrap_pi(self) -> Self {
+                let half = Self::half_turn();
+                let turn = half + half;
+                let wrapped = self.0 - ((self.0 + half) / turn).floor() * turn;
+                $angle(if wrapped <= -half { wrapped + turn } else { wrapped })
+            }
+
+            /// Returns the same direction as a positive angle below a full turn,
+            #[doc = concat!("in [0, ", $full, ")")]
+            pub fn wrap_two_pi(self) -> Self {
+                let turn = Self::half_turn() + Self::half_turn();
+                let wrapped = self.0 - (self.0 / turn).floor() * turn;
+                // A tiny negative angle rounds up to a whole turn
+                $angle(if wrapped >= turn { wrapped - turn } else { wrapped })
+            }
+        }
+
+        impl<T: Scalar> Add for $angle<T> {
+            type Output = $angle<T>;
+
+            fn add(self, rhs: $angle<T>) -> Self::Output {
+                $angle(self.0 + rhs.0)
+            }
+        }
+
+        /// Returns how many times "rhs" fits in the angle
+        impl<T: Scalar> Div for $angle<T> {
+            type Output = T;
+
+            fn div(self, rhs: $angle<T>) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $angle<T> {
+            type Output = $angle<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $angle(self.0 / rhs)
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $angle<T> {
+            type Output = $angle<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $angle(self.0 * rhs)
+            }
+        }
+
+        impl<T: Scalar + Neg<Output = T>> Neg for $angle<T> {
+            type Output = $angle<T>;
+
+            fn neg(self) -> Self::Output {
+                $angle(-self.0)
+            }
+        }
+
+        impl<T: Scalar> Sub for $angle<T> {
+            type Output = $angle<T>;
+
+            fn sub(self, rhs: $angle<T>) -> Self::Output {
+                $angle(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $angle<T> {
+            fn add_assign(&mut self, rhs: $angle<T>) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $angle<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $angle<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $angle<T> {
+            fn sub_assign(&mut self, rhs: $angle<T>) {
+                *self = *self - rhs;
+            }
+        }
+    )*};
+}
+
+impl_angle!(Radians: "π", "2π"; Degrees: "180", "360");
+
+impl<T: Real> From<Degrees<T>> for Radians<T> {
+    fn from(angle: Degrees<T>) -> Self {
+        Radians(angle.0.to_radians())
+    }
+}
+
+impl<T: Float> From<Radians<T>> for Degrees<T> {
+    fn from(angle: Radians<T>) -> Self {
+        Degrees(angle.0.to_degrees())
+    }
+}
+
+impl<T: Display> Display for Radians<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
+
+impl<T: Display> Display for Degrees<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
@@ -0,0 +1,279 @@
+//=============================================================================
+// approx.rs
+// Comparisons of floating point values with a tolerance
+//=============================================================================
+
+use crate::math::EPSILON;
+use crate::math::angle::{Degrees, Radians};
+use crate::math::fixed::Fixed;
+use crate::math::matrix::Matrix;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::Vector;
+
+/// Largest number of representable values between two floats
+/// that `ulps_eq` treats as equal by default
+pub const DEFAULT_MAX_ULPS: u32 = 4;
+
+/// Equality of values that may have picked up rounding errors
+pub trait ApproxEq {
+    /// The type the tolerance is given in, the float type the value is made of.
+    /// Its default is a zero tolerance
+    type Tolerance: Copy + Default;
+
+    /// The tolerance used by `approx_eq`
+    fn default_epsilon() -> Self::Tolerance;
+
+    /// Returns true if every component differs by at most "epsilon"
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool;
+
+    /// Returns true if every component differs by at most "epsilon", or by at most
+    /// "max_relative" times the largest of the two components
+    fn relative_eq(&self, other: &Self, epsilon: Self::Tolerance, max_relative: Self::Tolerance) -> bool;
+
+    /// Returns true if every component differs by at most "epsilon", or has at most
+    /// "max_ulps" representable values between them
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool;
+
+    /// Returns true if every component differs by at most `default_epsilon`
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.abs_diff_eq(other, Self::default_epsilon())
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($t:ident, $bits:ident) => {
+        impl ApproxEq for $t {
+            type Tolerance = $t;
+
+            fn default_epsilon() -> $t {
+                EPSILON as $t
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                // Also covers infinities of the same sign
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let largest = self.abs().max(other.abs());
+                (self - other).abs() <= largest * max_relative
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+
+                // Floats of the same sign are ordered like their bit patterns
+                let a = self.to_bits() as $bits;
+                let b = other.to_bits() as $bits;
+                (a - b).unsigned_abs() <= max_ulps as _
+            }
+        }
+    };
+}
+
+impl_approx_eq_float!(f32, i32);
+impl_approx_eq_float!(f64, i64);
+
+/// Tolerances are in fixed-point too, ulps are steps of `Fixed::RESOLUTION`
+impl ApproxEq for Fixed {
+    type Tolerance = Fixed;
+
+    fn default_epsilon() -> Fixed {
+        Fixed::RESOLUTION
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Fixed) -> bool {
+        (self.to_bits() as i64 - other.to_bits() as i64).abs() <= epsilon.to_bits() as i64
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Fixed, max_relative: Fixed) -> bool {
+        let largest = self.to_bits().unsigned_abs().max(other.to_bits().unsigned_abs()) as f64;
+        self.abs_diff_eq(other, epsilon)
+            || (self.to_bits() as i64 - other.to_bits() as i64).abs() as f64 <= largest * max_relative.to_f64()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Fixed, max_ulps: u32) -> bool {
+        self.abs_diff_eq(other, epsilon) || (self.to_bits() as i64 - other.to_bits() as i64).abs() <= max_ulps as i64
+    }
+}
+
+macro_rules! impl_approx_eq_angle {
+    ($($angle:ident),*) => {$(
+        /// Tolerances are in the unit of the angle
+        impl<T: ApproxEq> ApproxEq for $angle<T> {
+            type Tolerance = T::Tolerance;
+
+            fn default_epsilon() -> Self::Tolerance {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool {
+                self.0.abs_diff_eq(&other.0, epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Tolerance, max_relative: Self::Tolerance) -> bool {
+                self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool {
+                self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+    )*};
+}
+
+impl_approx_eq_angle!(Radians, Degrees);
+
+impl<T: ApproxEq, const N: usize> ApproxEq for Vector<T, N> {
+    type Tolerance = T::Tolerance;
+
+    fn default_epsilon() -> Self::Tolerance {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool {
+        self.values.iter().zip(other.values.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Tolerance, max_relative: Self::Tolerance) -> bool {
+        self.values.iter().zip(other.values.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool {
+        self.values.iter().zip(other.values.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for Matrix<T, N> {
+    type Tolerance = T::Tolerance;
+
+    fn default_epsilon() -> Self::Tolerance {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool {
+        self.values.iter().flatten().zip(other.values.iter().flatten()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Tolerance, max_relative: Self::Tolerance) -> bool {
+        self.values.iter().flatten().zip(other.values.iter().flatten())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool {
+        self.values.iter().flatten().zip(other.values.iter().flatten()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// Compares the raw components, a rotation and its negation are not considered equal
+impl<T: ApproxEq> ApproxEq for Quaternion<T> {
+    type Tolerance = T::Tolerance;
+
+    fn default_epsilon() -> Self::Tolerance {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool {
+        components(self).iter().zip(components(other).iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Tolerance, max_relative: Self::Tolerance) -> bool {
+        components(self).iter().zip(components(other).iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool {
+        components(self).iter().zip(components(other).iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+fn components<T>(q: &Quaternion<T>) -> [&T; 4] {
+    [&q.x, &q.y, &q.z, &q.w]
+}
+
+/// Returns the default tolerance for the type of "value", used by the assert macros
+#[doc(hidden)]
+pub fn default_epsilon_of<T: ApproxEq>(_value: &T) -> T::Tolerance {
+    T::default_epsilon()
+}
+
+/// Returns a zero tolerance for the type of "value", used by `assert_ulps_eq`
+#[doc(hidden)]
+pub fn zero_epsilon_of<T: ApproxEq>(_value: &T) -> T::Tolerance {
+    T::Tolerance::default()
+}
+
+/// Asserts that two values differ by at most an absolute tolerance,
+/// `EPSILON` unless one is given
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                $crate::assert_approx_eq!(*left, *right, $crate::math::approx::default_epsilon_of(left))
+            }
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::math::approx::ApproxEq::abs_diff_eq(left, right, $epsilon) {
+                    panic!("assertion failed: `left ~= right`\n  left: `{:?}`\n right: `{:?}`", left, right);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are within a relative tolerance of each other,
+/// `EPSILON` unless one is given
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                $crate::assert_relative_eq!(*left, *right, $crate::math::approx::default_epsilon_of(left))
+            }
+        }
+    };
+    ($left:expr, $right:expr, $max_relative:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let epsilon = $crate::math::approx::default_epsilon_of(left);
+                if !$crate::math::approx::ApproxEq::relative_eq(left, right, epsilon, $max_relative) {
+                    panic!("assertion failed: `left ~= right`\n  left: `{:?}`\n right: `{:?}`", left, right);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are at most a number of representable values apart,
+/// `DEFAULT_MAX_ULPS` unless one is given
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_ulps_eq!($left, $right, $crate::math::approx::DEFAULT_MAX_ULPS)
+    };
+    ($left:expr, $right:expr, $max_ulps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                // Only the ulps distance counts, not an absolute tolerance
+                if !$crate::math::approx::ApproxEq::ulps_eq(left, right, $crate::math::approx::zero_epsilon_of(left), $max_ulps) {
+                    panic!("assertion failed: `left ~= right`\n  left: `{:?}`\n right: `{:?}`", left, right);
+                }
+            }
+        }
+    };
+}
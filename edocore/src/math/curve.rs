@@ -0,0 +1,336 @@
+//=============================================================================
+// curve.rs
+// Smooth paths through vectors of any dimension
+//=============================================================================
+
+use alloc::vec::Vec;
+use crate::math::scalar::{Float, Scalar};
+use crate::math::vector::Vector;
+
+/// Number of samples used by `Curve::closest_parameter` before refining
+const CLOSEST_SAMPLES: usize = 32;
+/// Number of refinement steps used by `Curve::closest_parameter`
+const CLOSEST_ITERATIONS: usize = 24;
+
+/// A path parameterized by "t" in [0, 1]
+pub trait Curve<T: Float, const N: usize> {
+    /// Returns the point at "t"
+    fn evaluate(&self, t: T) -> Vector<T, N>;
+
+    /// Returns the first derivative, the velocity, at "t"
+    fn derivative(&self, t: T) -> Vector<T, N>;
+
+    /// Returns the length of the curve approximated with "samples" straight segments
+    fn arc_length(&self, samples: usize) -> T {
+        ArcLengthTable::new(self, samples).length()
+    }
+
+    /// Returns the parameter of the point on the curve closest to "point"
+    fn closest_parameter(&self, point: Vector<T, N>) -> T {
+        let distance = |t: T| (self.evaluate(t) - point).square_magnitude();
+        let step = T::ONE / T::from_f32(CLOSEST_SAMPLES as f32);
+
+        // Find the best sample, then bisect around it
+        let mut best = T::ZERO;
+        let mut best_distance = distance(T::ZERO);
+        for i in 1..=CLOSEST_SAMPLES {
+            let t = T::from_f32(i as f32) * step;
+            let d = distance(t);
+            if d < best_distance {
+                best = t;
+                best_distance = d;
+            }
+        }
+
+        // The distance is smallest where the velocity is perpendicular to the offset
+        let mut low = clamp01(best - step);
+        let mut high = clamp01(best + step);
+        for _ in 0..CLOSEST_ITERATIONS {
+            let middle = (low + high) / T::from_f32(2.0);
+            if Vector::dot(self.evaluate(middle) - point, self.derivative(middle)) < T::ZERO {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        (low + high) / T::from_f32(2.0)
+    }
+
+    /// Returns the point on the curve closest to "point"
+    fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        self.evaluate(self.closest_parameter(point))
+    }
+}
+
+/// Maps distances along a curve to parameters so it can be travelled at a constant speed
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArcLengthTable<T> {
+    /// Distance from the start of the curve at each of the evenly spaced parameters
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::math::serialize::deserialize_at_least::<_, _, 2>", bound(deserialize = "T: serde::Deserialize<'de>")))]
+    lengths: Vec<T>,
+}
+
+impl<T: Float> ArcLengthTable<T> {
+    /// Returns the total length of the curve
+    pub fn length(&self) -> T {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Measures a curve with "samples" straight segments
+    pub fn new<C: Curve<T, N> + ?Sized, const N: usize>(curve: &C, samples: usize) -> ArcLengthTable<T> {
+        let samples = samples.max(1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut previous = curve.evaluate(T::ZERO);
+        let mut total = T::ZERO;
+        lengths.push(total);
+
+        for i in 1..=samples {
+            let point = curve.evaluate(T::from_f32(i as f32) / T::from_f32(samples as f32));
+            total = total + (point - previous).magnitude();
+            lengths.push(total);
+            previous = point;
+        }
+
+        ArcLengthTable { lengths }
+    }
+
+    /// Returns the parameter at "distance" along the curve
+    pub fn parameter(&self, distance: T) -> T {
+        let segments = self.lengths.len() - 1;
+        if distance <= T::ZERO {
+            return T::ZERO;
+        }
+        if distance >= self.length() {
+            return T::ONE;
+        }
+
+        // First sample that is at least "distance" along the curve
+        let index = self.lengths.partition_point(|&length| length < distance).max(1);
+        let start = self.lengths[index - 1];
+        let span = self.lengths[index] - start;
+        let fraction = if span > T::ZERO { (distance - start) / span } else { T::ZERO };
+
+        (T::from_f32((index - 1) as f32) + fraction) / T::from_f32(segments as f32)
+    }
+}
+
+/// A Bezier curve with one control point
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadraticBezier<T, const N: usize> {
+    pub p0: Vector<T, N>,
+    pub p1: Vector<T, N>,
+    pub p2: Vector<T, N>,
+}
+
+/// A Bezier curve with two control points
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier<T, const N: usize> {
+    pub p0: Vector<T, N>,
+    pub p1: Vector<T, N>,
+    pub p2: Vector<T, N>,
+    pub p3: Vector<T, N>,
+}
+
+/// A cubic curve defined by its end points and the tangents at them
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hermite<T, const N: usize> {
+    pub p0: Vector<T, N>,
+    pub m0: Vector<T, N>,
+    pub p1: Vector<T, N>,
+    pub m1: Vector<T, N>,
+}
+
+/// A uniform Catmull-Rom spline passing through every point.
+/// Needs at least two points, each pair of neighbours takes an equal share of [0, 1]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatmullRom<T, const N: usize> {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::math::serialize::deserialize_at_least::<_, _, 2>", bound(deserialize = "T: serde::Deserialize<'de>")))]
+    points: Vec<Vector<T, N>>,
+}
+
+/// A uniform cubic B-spline, smooth but not passing through its points.
+/// Needs at least four points, each segment takes an equal share of [0, 1]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BSpline<T, const N: usize> {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::math::serialize::deserialize_at_least::<_, _, 4>", bound(deserialize = "T: serde::Deserialize<'de>")))]
+    points: Vec<Vector<T, N>>,
+}
+
+impl<T: Float, const N: usize> QuadraticBezier<T, N> {
+    /// Creates a new curve
+    pub fn new(p0: Vector<T, N>, p1: Vector<T, N>, p2: Vector<T, N>) -> Self {
+        QuadraticBezier { p0, p1, p2 }
+    }
+}
+
+impl<T: Float, const N: usize> Curve<T, N> for QuadraticBezier<T, N> {
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let u = T::ONE - t;
+        self.p0 * (u * u) + self.p1 * (T::from_f32(2.0) * u * t) + self.p2 * (t * t)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let two = T::from_f32(2.0);
+        (self.p1 - self.p0) * (two * (T::ONE - t)) + (self.p2 - self.p1) * (two * t)
+    }
+}
+
+impl<T: Float, const N: usize> CubicBezier<T, N> {
+    /// Creates a new curve
+    pub fn new(p0: Vector<T, N>, p1: Vector<T, N>, p2: Vector<T, N>, p3: Vector<T, N>) -> Self {
+        CubicBezier { p0, p1, p2, p3 }
+    }
+}
+
+impl<T: Float, const N: usize> Curve<T, N> for CubicBezier<T, N> {
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let u = T::ONE - t;
+        let three = T::from_f32(3.0);
+        self.p0 * (u * u * u) + self.p1 * (three * u * u * t) + self.p2 * (three * u * t * t) + self.p3 * (t * t * t)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let u = T::ONE - t;
+        let three = T::from_f32(3.0);
+        let six = T::from_f32(6.0);
+        (self.p1 - self.p0) * (three * u * u) + (self.p2 - self.p1) * (six * u * t) + (self.p3 - self.p2) * (three * t * t)
+    }
+}
+
+impl<T: Float, const N: usize> Hermite<T, N> {
+    /// Creates a new curve
+    pub fn new(p0: Vector<T, N>, m0: Vector<T, N>, p1: Vector<T, N>, m1: Vector<T, N>) -> Self {
+        Hermite { p0, m0, p1, m1 }
+    }
+}
+
+impl<T: Float, const N: usize> Curve<T, N> for Hermite<T, N> {
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let (two, three) = (T::from_f32(2.0), T::from_f32(3.0));
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        self.p0 * (two * t3 - three * t2 + T::ONE)
+            + self.m0 * (t3 - two * t2 + t)
+            + self.p1 * (three * t2 - two * t3)
+            + self.m1 * (t3 - t2)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let (two, three, four, six) = (T::from_f32(2.0), T::from_f32(3.0), T::from_f32(4.0), T::from_f32(6.0));
+        let t2 = t * t;
+
+        self.p0 * (six * t2 - six * t)
+            + self.m0 * (three * t2 - four * t + T::ONE)
+            + self.p1 * (six * t - six * t2)
+            + self.m1 * (three * t2 - two * t)
+    }
+}
+
+impl<T: Float, const N: usize> CatmullRom<T, N> {
+    /// Creates a new spline through "points"
+    pub fn new(points: Vec<Vector<T, N>>) -> Self {
+        assert!(points.len() >= 2, "a Catmull-Rom spline needs at least two points");
+        CatmullRom { points }
+    }
+
+    /// Returns the points the spline passes through
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+
+    /// Returns the Hermite curve between points "index" and "index + 1"
+    fn segment(&self, index: usize) -> Hermite<T, N> {
+        let last = self.points.len() - 1;
+        let p0 = self.points[index];
+        let p1 = self.points[(index + 1).min(last)];
+        let before = self.points[index.saturating_sub(1)];
+        let after = self.points[(index + 2).min(last)];
+        let half = T::from_f32(0.5);
+
+        Hermite::new(p0, (p1 - before) * half, p1, (after - p0) * half)
+    }
+}
+
+impl<T: Float, const N: usize> Curve<T, N> for CatmullRom<T, N> {
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let (index, local) = split_parameter(t, self.points.len() - 1);
+        self.segment(index).evaluate(local)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let segments = self.points.len() - 1;
+        let (index, local) = split_parameter(t, segments);
+        self.segment(index).derivative(local) * T::from_f32(segments as f32)
+    }
+}
+
+impl<T: Float, const N: usize> BSpline<T, N> {
+    /// Creates a new spline controlled by "points"
+    pub fn new(points: Vec<Vector<T, N>>) -> Self {
+        assert!(points.len() >= 4, "a cubic B-spline needs at least four points");
+        BSpline { points }
+    }
+
+    /// Returns the points controlling the spline
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+
+    fn segment_points(&self, index: usize) -> [Vector<T, N>; 4] {
+        [self.points[index], self.points[index + 1], self.points[index + 2], self.points[index + 3]]
+    }
+}
+
+impl<T: Float, const N: usize> Curve<T, N> for BSpline<T, N> {
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let (index, t) = split_parameter(t, self.points.len() - 3);
+        let [p0, p1, p2, p3] = self.segment_points(index);
+        let u = T::ONE - t;
+        let (t2, t3) = (t * t, t * t * t);
+        let (three, four, six) = (T::from_f32(3.0), T::from_f32(4.0), T::from_f32(6.0));
+
+        (p0 * (u * u * u)
+            + p1 * (three * t3 - six * t2 + four)
+            + p2 * (-three * t3 + three * t2 + three * t + T::ONE)
+            + p3 * t3) / six
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let segments = self.points.len() - 3;
+        let (index, t) = split_parameter(t, segments);
+        let [p0, p1, p2, p3] = self.segment_points(index);
+        let u = T::ONE - t;
+        let t2 = t * t;
+        let (two, three, four) = (T::from_f32(2.0), T::from_f32(3.0), T::from_f32(4.0));
+
+        (p0 * (-u * u)
+            + p1 * (three * t2 - four * t)
+            + p2 * (-three * t2 + two * t + T::ONE)
+            + p3 * t2) / two * T::from_f32(segments as f32)
+    }
+}
+
+/// Splits a global parameter into a segment index and the parameter within that segment
+fn split_parameter<T: Float>(t: T, segments: usize) -> (usize, T) {
+    let scaled = clamp01(t) * T::from_f32(segments as f32);
+    let index = (scaled.to_f32() as usize).min(segments - 1);
+    (index, scaled - T::from_f32(index as f32))
+}
+
+fn clamp01<T: Scalar>(t: T) -> T {
+    if t < T::ZERO {
+        T::ZERO
+    } else if t > T::ONE {
+        T::ONE
+    } else {
+        t
+    }
+}
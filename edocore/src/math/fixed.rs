@@ -0,0 +1,274 @@
+//=============================================================================
+// fixed.rs
+// Fixed-point numbers for deterministic simulation
+//
+// Integer arithmetic gives the same bits on every compiler and platform, which
+// lockstep simulations need and floats can't promise. Since `Fixed` implements
+// `Float`, vectors and matrices of it have the same API as the float ones.
+//=============================================================================
+
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::math::matrix::Matrix;
+use crate::math::scalar::{Float, Scalar};
+use crate::math::vector::Vector;
+
+/// The bits of the fractional part
+const FRACTION_MASK: i32 = (1 << Fixed::FRACTIONAL_BITS) - 1;
+
+/// A signed Q16.16 number, 16 integer bits and 16 fractional bits in an i32.
+/// It covers [-32768, 32768) in steps of 1/65536.
+///
+/// Arithmetic wraps on overflow in every build so that results never depend on
+/// the build profile. Multiplication rounds to the nearest value, division rounds
+/// toward zero and panics when dividing by zero
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// Number of fractional bits
+    pub const FRACTIONAL_BITS: u32 = 16;
+    /// The largest value
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    /// The smallest value
+    pub const MIN: Fixed = Fixed(i32::MIN);
+    /// The distance between two neighbouring values
+    pub const RESOLUTION: Fixed = Fixed(1);
+
+    /// Creates a value from its raw representation, the value times 65536
+    pub const fn from_bits(bits: i32) -> Fixed {
+        Fixed(bits)
+    }
+
+    /// Converts an f64 into the nearest value, saturating at the ends of the range
+    pub fn from_f64(value: f64) -> Fixed {
+        Fixed((value * (1 << Self::FRACTIONAL_BITS) as f64).round() as i32)
+    }
+
+    /// Converts an integer, wrapping if it is outside of the range
+    pub const fn from_i32(value: i32) -> Fixed {
+        Fixed(value.wrapping_shl(Self::FRACTIONAL_BITS))
+    }
+
+    /// Returns the raw representation, the value times 65536
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Converts into an f64, exactly
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1 << Self::FRACTIONAL_BITS) as f64
+    }
+
+    /// Returns the integer part, rounded toward negative infinity
+    pub const fn to_i32(self) -> i32 {
+        self.0 >> Self::FRACTIONAL_BITS
+    }
+}
+
+impl Scalar for Fixed {
+    const ZERO: Self = Fixed(0);
+    const ONE: Self = Fixed(1 << Fixed::FRACTIONAL_BITS);
+
+    /// Sums the exact products in an i128 and rounds once, so a partial sum can't
+    /// overflow. A result outside of the range saturates
+    fn dot<const N: usize>(lhs: Vector<Self, N>, rhs: Vector<Self, N>) -> Self {
+        let mut sum = 0i128;
+        for i in 0..N {
+            sum += lhs.values[i].0 as i128 * rhs.values[i].0 as i128;
+        }
+        saturate((sum + (1 << (Self::FRACTIONAL_BITS - 1))) >> Self::FRACTIONAL_BITS)
+    }
+}
+
+impl Float for Fixed {
+    /// The resolution, the smallest value above zero
+    const EPSILON: Self = Fixed::RESOLUTION;
+    const MACHINE_EPSILON: Self = Fixed::RESOLUTION;
+
+    /// Converts an f32 into the nearest value, saturating at the ends of the range
+    fn from_f32(value: f32) -> Self {
+        Fixed::from_f64(value as f64)
+    }
+
+    /// Converts into an f32, rounding away the lowest bits of large values
+    fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    fn abs(self) -> Self {
+        Fixed(self.0.wrapping_abs())
+    }
+
+    /// Polynomial approximation (Abramowitz and Stegun 4.4.45) in fixed-point
+    /// arithmetic, accurate to about 0.0001 radians
+    fn acos(self) -> Self {
+        let x = self.abs().min(Fixed::ONE);
+        let polynomial = ((Fixed(-1227) * x + Fixed(4867)) * x - Fixed(13901)) * x + Fixed(102_939);
+        let angle = (Fixed::ONE - x).sqrt() * polynomial;
+
+        if self < Fixed::ZERO {
+            Fixed(205_887) - angle
+        } else {
+            angle
+        }
+    }
+
+    fn ceil(self) -> Self {
+        Fixed(self.0.wrapping_add(FRACTION_MASK) & !FRACTION_MASK)
+    }
+
+    fn floor(self) -> Self {
+        Fixed(self.0 & !FRACTION_MASK)
+    }
+
+    /// Takes the root of the exact square magnitude, so vectors longer than about
+    /// 181, whose square magnitude is out of the range, still work. Rounded down,
+    /// saturating at `Fixed::MAX`
+    fn magnitude<const N: usize>(vector: Vector<Self, N>) -> Self {
+        // The components are the values times 2^16, so the root is the magnitude times 2^16
+        let sum: u128 = vector.values.iter().map(|v| v.0.unsigned_abs() as u128 * v.0.unsigned_abs() as u128).sum();
+        saturate(integer_sqrt(sum) as i128)
+    }
+
+    fn round(self) -> Self {
+        let half = Fixed::ONE.0 / 2;
+        if self.0 >= 0 {
+            Fixed(self.0.wrapping_add(half) & !FRACTION_MASK)
+        } else {
+            -Fixed(self.0.wrapping_neg().wrapping_add(half) & !FRACTION_MASK)
+        }
+    }
+
+    /// Rounded down integer square root, zero for negative values
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+
+        // The root of value * 2^32 is the root of value, times 2^16
+        Fixed(integer_sqrt((self.0 as u128) << Self::FRACTIONAL_BITS) as i32)
+    }
+
+    fn to_degrees(self) -> Self {
+        self * Fixed(3_754_936)
+    }
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, rhs: Fixed) -> Self::Output {
+        Fixed(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    fn div(self, rhs: Fixed) -> Self::Output {
+        Fixed((((self.0 as i64) << Self::FRACTIONAL_BITS) / rhs.0 as i64) as i32)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        let product = self.0 as i64 * rhs.0 as i64;
+        Fixed(((product + (1 << (Self::FRACTIONAL_BITS - 1))) >> Self::FRACTIONAL_BITS) as i32)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Self::Output {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, rhs: Fixed) -> Self::Output {
+        Fixed(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+macro_rules! impl_assign {
+    ($($assign:ident, $assign_fn:ident, $op_fn:ident);*) => {$(
+        impl $assign for Fixed {
+            fn $assign_fn(&mut self, rhs: Fixed) {
+                *self = self.$op_fn(rhs);
+            }
+        }
+    )*};
+}
+
+impl_assign!(AddAssign, add_assign, add; DivAssign, div_assign, div; MulAssign, mul_assign, mul; SubAssign, sub_assign, sub);
+
+impl From<i32> for Fixed {
+    /// Converts an integer, see `Fixed::from_i32`
+    fn from(value: i32) -> Self {
+        Fixed::from_i32(value)
+    }
+}
+
+/// Clamps a wide raw value into the range
+fn saturate(bits: i128) -> Fixed {
+    Fixed(bits.clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+}
+
+/// Rounded down square root, digit by digit
+fn integer_sqrt(value: u128) -> u128 {
+    let mut root = 0u128;
+    let mut bit = 1u128 << 126;
+    while bit > value {
+        bit >>= 2;
+    }
+
+    let mut rest = value;
+    while bit != 0 {
+        if rest >= root + bit {
+            rest -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+impl<const N: usize> Vector<Fixed, N> {
+    /// Converts a float vector into the nearest fixed-point vector
+    pub fn from_f32(vector: Vector<f32, N>) -> Self {
+        vector.map(Fixed::from_f32)
+    }
+
+    /// Converts into a float vector, for rendering
+    pub fn to_f32(self) -> Vector<f32, N> {
+        self.map(Fixed::to_f32)
+    }
+}
+
+impl<const N: usize> Matrix<Fixed, N> {
+    /// Converts a float matrix into the nearest fixed-point matrix
+    pub fn from_f32(matrix: Matrix<f32, N>) -> Self {
+        Matrix { values: matrix.values.map(|row| row.map(Fixed::from_f32)) }
+    }
+
+    /// Converts into a float matrix, for rendering
+    pub fn to_f32(self) -> Matrix<f32, N> {
+        Matrix { values: self.values.map(|row| row.map(Fixed::to_f32)) }
+    }
+}
@@ -0,0 +1,202 @@
+//=============================================================================
+// float.rs
+// Float functions that need either the standard library or libm
+//
+// core has no floor, sqrt or trigonometry for f32 and f64. With "std" these
+// methods forward to the inherent ones, otherwise to libm. Code working on
+// concrete floats calls them through the trait, as in `FloatMath::sqrt(x)`, so
+// the same call resolves the same way whether "std" is on or not.
+//=============================================================================
+
+/// The float functions missing from core
+pub(crate) trait FloatMath: Sized {
+    fn acos(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn cbrt(self) -> Self;
+    fn ceil(self) -> Self;
+    fn cos(self) -> Self;
+    fn floor(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn sqrt(self) -> Self;
+    fn tan(self) -> Self;
+    fn trunc(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_math {
+    ($($t:ident),*) => {$(
+        impl FloatMath for $t {
+            #[inline]
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn cbrt(self) -> Self {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $t::rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "std")]
+impl_float_math!(f32, f64);
+
+/// libm names the f32 functions with an "f" suffix
+#[cfg(not(feature = "std"))]
+macro_rules! impl_float_math {
+    ($($t:ident $(=> $suffix:ident)?),*) => {$(
+        paste::paste! {
+            impl FloatMath for $t {
+                #[inline]
+                fn acos(self) -> Self {
+                    libm::[<acos $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn asin(self) -> Self {
+                    libm::[<asin $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    libm::[<atan2 $($suffix)?>](self, other)
+                }
+
+                #[inline]
+                fn cbrt(self) -> Self {
+                    libm::[<cbrt $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn ceil(self) -> Self {
+                    libm::[<ceil $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    libm::[<cos $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    libm::[<floor $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn powf(self, n: Self) -> Self {
+                    libm::[<pow $($suffix)?>](self, n)
+                }
+
+                #[inline]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    let r = self % rhs;
+                    if r < 0.0 { r + rhs.abs() } else { r }
+                }
+
+                #[inline]
+                fn round(self) -> Self {
+                    libm::[<round $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    libm::[<sin $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    libm::[<sincos $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    libm::[<sqrt $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn tan(self) -> Self {
+                    libm::[<tan $($suffix)?>](self)
+                }
+
+                #[inline]
+                fn trunc(self) -> Self {
+                    libm::[<trunc $($suffix)?>](self)
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(not(feature = "std"))]
+impl_float_math!(f32 => f, f64);
@@ -0,0 +1,513 @@
+//=============================================================================
+// geom2d.rs
+// Segments and polygons in the plane
+//
+// Polygons are slices of vertices without the first vertex repeated at the end.
+// They must be simple, edges only meet the neighbouring edges at their shared
+// vertices. Either winding is accepted, results are counter-clockwise.
+//=============================================================================
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use crate::math::EPSILON;
+use crate::math::vector::Vector2;
+
+/// The direction in which the vertices of a polygon go around it
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A line segment between two points
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    pub a: Vector2,
+    pub b: Vector2,
+}
+
+/// The points two segments have in common
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SegmentIntersection {
+    /// The segments cross or touch at a single point
+    Point(Vector2),
+    /// The segments are collinear and share a part, running the same way as the first segment
+    Overlap(Segment),
+}
+
+impl Segment {
+    /// Returns the point on the segment closest to "point"
+    pub fn closest_point(self, point: Vector2) -> Vector2 {
+        let direction = self.b - self.a;
+        let sqr_len = direction.square_magnitude();
+        if sqr_len == 0.0 {
+            return self.a;
+        }
+
+        let t = Vector2::dot(point - self.a, direction) / sqr_len;
+        self.point_at(t.clamp(0.0, 1.0))
+    }
+
+    /// Returns the points the two segments have in common, if any. Points
+    /// closer than `EPSILON` count as touching
+    pub fn intersect(self, other: Segment) -> Option<SegmentIntersection> {
+        match intersect_parameters(self, other)? {
+            Parameters::Point(t, u) => Some(SegmentIntersection::Point(shared_point(self, other, t, u))),
+            Parameters::Overlap([(t0, u0), (t1, u1)]) => Some(SegmentIntersection::Overlap(Segment::new(
+                shared_point(self, other, t0, u0),
+                shared_point(self, other, t1, u1),
+            ))),
+        }
+    }
+
+    /// Returns the length of the segment
+    pub fn length(self) -> f32 {
+        Vector2::distance(self.a, self.b)
+    }
+
+    /// Creates a segment from "a" to "b"
+    #[inline]
+    pub fn new(a: Vector2, b: Vector2) -> Segment {
+        Segment { a, b }
+    }
+
+    /// Returns the point at "t" along the segment, exactly "a" at 0 and "b" at 1
+    pub fn point_at(self, t: f32) -> Vector2 {
+        if t == 1.0 {
+            self.b
+        } else {
+            self.a + (self.b - self.a) * t
+        }
+    }
+}
+
+/// Returns the area of a polygon, positive if it is counter-clockwise
+pub fn signed_area(polygon: &[Vector2]) -> f32 {
+    edges(polygon).map(|edge| cross(edge.a, edge.b)).sum::<f32>() * 0.5
+}
+
+/// Returns the area of a polygon
+pub fn area(polygon: &[Vector2]) -> f32 {
+    signed_area(polygon).abs()
+}
+
+/// Returns the winding of a polygon, None if it has no area
+pub fn winding(polygon: &[Vector2]) -> Option<Winding> {
+    let area = signed_area(polygon);
+    if area > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else if area < 0.0 {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// Returns true if "point" is inside the polygon.
+/// Points on the boundary may be counted on either side
+pub fn contains_point(polygon: &[Vector2], point: Vector2) -> bool {
+    let mut inside = false;
+    for edge in edges(polygon) {
+        // Count the edges crossed by a ray going toward +x
+        if (edge.a.y > point.y) != (edge.b.y > point.y) {
+            let x = edge.a.x + (point.y - edge.a.y) * (edge.b.x - edge.a.x) / (edge.b.y - edge.a.y);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Returns the smallest convex polygon containing every point, counter-clockwise
+/// and without collinear vertices, using Andrew's monotone chain
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal).then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // The lower chain from left to right, then the upper chain back
+    let mut hull: Vec<Vector2> = Vec::with_capacity(sorted.len() + 1);
+    for pass in 0..2 {
+        let start = hull.len();
+        for &point in sorted.iter() {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 1] - hull[hull.len() - 2], point - hull[hull.len() - 1]) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point of a chain is the first of the other
+        hull.pop();
+        if pass == 0 {
+            sorted.reverse();
+        }
+    }
+    hull
+}
+
+/// Splits a polygon with holes into triangles by ear clipping, holes being
+/// joined to the outline first. Returns counter-clockwise triangles of indices
+/// into the vertices of "outer" followed by the vertices of each hole in order
+pub fn triangulate(outer: &[Vector2], holes: &[&[Vector2]]) -> Vec<[usize; 3]> {
+    let mut points = outer.to_vec();
+    let mut ring: Vec<usize> = (0..outer.len()).collect();
+    if signed_area(outer) < 0.0 {
+        ring.reverse();
+    }
+
+    // Every hole keeps its vertices so the indices stay as documented, but holes
+    // without area are not joined to the outline
+    let mut hole_rings = Vec::with_capacity(holes.len());
+    for hole in holes.iter() {
+        let mut hole_ring: Vec<usize> = (points.len()..points.len() + hole.len()).collect();
+        points.extend_from_slice(hole);
+        if hole.len() < 3 {
+            continue;
+        }
+        if signed_area(hole) > 0.0 {
+            hole_ring.reverse();
+        }
+        hole_rings.push(hole_ring);
+    }
+
+    // Holes further right are joined first so later bridges can't cross them
+    let max_x = |ring: &Vec<usize>| ring.iter().map(|&i| points[i].x).fold(f32::NEG_INFINITY, f32::max);
+    hole_rings.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap_or(Ordering::Equal));
+    for hole_ring in hole_rings {
+        ring = bridge_hole(&points, ring, &hole_ring);
+    }
+
+    clip_ears(&points, ring)
+}
+
+/// Returns the area covered by either polygon, as outlines counter-clockwise and holes clockwise
+pub fn union(a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    boolean(a, b, Operation::Union)
+}
+
+/// Returns the area covered by both polygons, as counter-clockwise outlines
+pub fn intersection(a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    boolean(a, b, Operation::Intersection)
+}
+
+/// Returns the area covered by "a" but not "b", as outlines counter-clockwise and holes clockwise
+pub fn difference(a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    boolean(a, b, Operation::Difference)
+}
+
+/// Returns the z component of the cross product of two 2D vectors
+fn cross(a: Vector2, b: Vector2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Returns the edges of a polygon, the last one closing it
+fn edges(polygon: &[Vector2]) -> impl Iterator<Item = Segment> + '_ {
+    (0..polygon.len()).map(move |i| Segment::new(polygon[i], polygon[(i + 1) % polygon.len()]))
+}
+
+/// Returns a copy of a polygon wound counter-clockwise
+fn counter_clockwise(polygon: &[Vector2]) -> Vec<Vector2> {
+    let mut polygon = polygon.to_vec();
+    if signed_area(&polygon) < 0.0 {
+        polygon.reverse();
+    }
+    polygon
+}
+
+/// Where two segments meet, as pairs of parameters along the first and the second
+enum Parameters {
+    Point(f32, f32),
+    Overlap([(f32, f32); 2]),
+}
+
+/// Moves a parameter within "tolerance" of an end of the segment onto that end
+fn snap(t: f32, tolerance: f32) -> f32 {
+    if t.abs() <= tolerance {
+        0.0
+    } else if (t - 1.0).abs() <= tolerance {
+        1.0
+    } else {
+        t.clamp(0.0, 1.0)
+    }
+}
+
+fn intersect_parameters(s: Segment, o: Segment) -> Option<Parameters> {
+    let (d1, d2) = (s.b - s.a, o.b - o.a);
+    let (len1, len2) = (d1.magnitude(), d2.magnitude());
+    if len1 == 0.0 || len2 == 0.0 {
+        // A degenerate segment is a point, which touches the other segment or not
+        let (point, segment) = if len1 == 0.0 { (s.a, o) } else { (o.a, s) };
+        if Vector2::distance(segment.closest_point(point), point) > EPSILON {
+            return None;
+        }
+        let project = |segment: Segment, sqr_len: f32| {
+            if sqr_len == 0.0 { 0.0 } else { snap(Vector2::dot(point - segment.a, segment.b - segment.a) / sqr_len, 0.0) }
+        };
+        return Some(Parameters::Point(project(s, len1 * len1), project(o, len2 * len2)));
+    }
+
+    let (tolerance1, tolerance2) = (EPSILON / len1, EPSILON / len2);
+    let offset = o.a - s.a;
+    let denominator = cross(d1, d2);
+
+    if denominator.abs() > EPSILON * len1 * len2 {
+        let t = cross(offset, d2) / denominator;
+        let u = cross(offset, d1) / denominator;
+        if t < -tolerance1 || t > 1.0 + tolerance1 || u < -tolerance2 || u > 1.0 + tolerance2 {
+            return None;
+        }
+        return Some(Parameters::Point(snap(t, tolerance1), snap(u, tolerance2)));
+    }
+
+    // Parallel, they can only meet if they are on the same line
+    if cross(offset, d1).abs() > EPSILON * len1 {
+        return None;
+    }
+
+    // The shared part goes between the endpoints lying on both segments
+    let on_s = |point: Vector2| Vector2::dot(point - s.a, d1) / (len1 * len1);
+    let on_o = |point: Vector2| Vector2::dot(point - o.a, d2) / (len2 * len2);
+    let mut shared: Vec<(f32, f32)> = [(0.0, on_o(s.a)), (1.0, on_o(s.b)), (on_s(o.a), 0.0), (on_s(o.b), 1.0)]
+        .iter()
+        .filter(|(t, u)| *t >= -tolerance1 && *t <= 1.0 + tolerance1 && *u >= -tolerance2 && *u <= 1.0 + tolerance2)
+        .map(|&(t, u)| (snap(t, tolerance1), snap(u, tolerance2)))
+        .collect();
+    shared.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let (first, last) = (*shared.first()?, *shared.last()?);
+    if last.0 - first.0 <= tolerance1 {
+        Some(Parameters::Point(first.0, first.1))
+    } else {
+        Some(Parameters::Overlap([first, last]))
+    }
+}
+
+/// Returns the point at the given parameters, using a vertex exactly when it is one,
+/// so that both segments are split at the same point
+fn shared_point(s: Segment, o: Segment, t: f32, u: f32) -> Vector2 {
+    if u == 0.0 || u == 1.0 {
+        o.point_at(u)
+    } else {
+        s.point_at(t)
+    }
+}
+
+/// Returns true if "point" is inside or on the edges of a triangle of either winding
+fn triangle_contains(point: Vector2, a: Vector2, b: Vector2, c: Vector2) -> bool {
+    let (ab, bc, ca) = (cross(b - a, point - a), cross(c - b, point - b), cross(a - c, point - c));
+    (ab >= 0.0 && bc >= 0.0 && ca >= 0.0) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0)
+}
+
+/// Joins a clockwise hole to a counter-clockwise ring through a pair of edges
+/// between the rightmost hole vertex and a ring vertex it can see
+fn bridge_hole(points: &[Vector2], ring: Vec<usize>, hole: &[usize]) -> Vec<usize> {
+    let start = (0..hole.len()).max_by(|&a, &b| points[hole[a]].x.partial_cmp(&points[hole[b]].x).unwrap_or(Ordering::Equal)).unwrap();
+    let m = points[hole[start]];
+    let n = ring.len();
+
+    // The closest edge hit by a ray from m toward +x
+    let mut hit: Option<(f32, usize)> = None;
+    for i in 0..n {
+        let (p, q) = (points[ring[i]], points[ring[(i + 1) % n]]);
+        if (p.y < m.y && q.y < m.y) || (p.y > m.y && q.y > m.y) || p.y == q.y {
+            continue;
+        }
+
+        let x = p.x + (m.y - p.y) * (q.x - p.x) / (q.y - p.y);
+        if x >= m.x && hit.is_none_or(|(best, _)| x < best) {
+            hit = Some((x, i));
+        }
+    }
+
+    let (x, edge) = match hit {
+        Some(hit) => hit,
+        // The hole is outside of the polygon
+        None => return ring,
+    };
+
+    // The end of the hit edge further right is visible unless a reflex vertex
+    // is in the way, in which case the one closest in angle to the ray is
+    let intersection = Vector2::new(x, m.y);
+    let mut visible = if points[ring[edge]].x > points[ring[(edge + 1) % n]].x { edge } else { (edge + 1) % n };
+    let candidate = points[ring[visible]];
+    let mut best_slope = f32::INFINITY;
+    for i in 0..n {
+        let v = points[ring[i]];
+        let (prev, next) = (points[ring[(i + n - 1) % n]], points[ring[(i + 1) % n]]);
+        let reflex = cross(v - prev, next - v) <= 0.0;
+        if i == visible || !reflex || v.x < m.x || !triangle_contains(v, m, intersection, candidate) {
+            continue;
+        }
+
+        let slope = (v.y - m.y).abs() / (v.x - m.x).max(EPSILON);
+        if slope < best_slope {
+            best_slope = slope;
+            visible = i;
+        }
+    }
+
+    // ... visible, m, the rest of the hole, m, visible, ...
+    let mut bridged = Vec::with_capacity(n + hole.len() + 2);
+    bridged.extend_from_slice(&ring[..=visible]);
+    bridged.extend((0..=hole.len()).map(|k| hole[(start + k) % hole.len()]));
+    bridged.extend_from_slice(&ring[visible..]);
+    bridged
+}
+
+/// Triangulates a counter-clockwise ring by cutting off one ear at a time
+fn clip_ears(points: &[Vector2], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut i = 0;
+    let mut misses = 0;
+
+    while ring.len() > 3 {
+        let n = ring.len();
+        let (prev, current, next) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        let (a, b, c) = (points[prev], points[current], points[next]);
+        let convex = cross(b - a, c - b) > 0.0;
+        let is_ear = convex && ring.iter().all(|&k| {
+            let p = points[k];
+            p == a || p == b || p == c || !triangle_contains(p, a, b, c)
+        });
+
+        if is_ear || misses >= n {
+            // Without any ear left the ring is degenerate, cutting anyway still terminates
+            if convex {
+                triangles.push([prev, current, next]);
+            }
+            ring.remove(i);
+            misses = 0;
+            if i >= ring.len() {
+                i = 0;
+            }
+        } else {
+            i = (i + 1) % n;
+            misses += 1;
+        }
+    }
+
+    if ring.len() == 3 && cross(points[ring[1]] - points[ring[0]], points[ring[2]] - points[ring[1]]) > 0.0 {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+    triangles
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// Splits both outlines where they meet, keeps the pieces that bound the result
+/// and links them back into closed outlines
+fn boolean(a: &[Vector2], b: &[Vector2], operation: Operation) -> Vec<Vec<Vector2>> {
+    let (a, b) = (counter_clockwise(a), counter_clockwise(b));
+    let (edges_a, edges_b): (Vec<Segment>, Vec<Segment>) = (edges(&a).collect(), edges(&b).collect());
+
+    let mut splits_a = vec![Vec::new(); edges_a.len()];
+    let mut splits_b = vec![Vec::new(); edges_b.len()];
+    for (i, &edge_a) in edges_a.iter().enumerate() {
+        for (j, &edge_b) in edges_b.iter().enumerate() {
+            let pairs = match intersect_parameters(edge_a, edge_b) {
+                Some(Parameters::Point(t, u)) => vec![(t, u)],
+                Some(Parameters::Overlap(pairs)) => pairs.to_vec(),
+                None => continue,
+            };
+            for (t, u) in pairs {
+                let point = shared_point(edge_a, edge_b, t, u);
+                splits_a[i].push((t, point));
+                splits_b[j].push((u, point));
+            }
+        }
+    }
+
+    let mut kept = Vec::new();
+    for piece in split_edges(&edges_a, splits_a) {
+        let keep = match boundary_direction(piece, &edges_b) {
+            // Shared edges are kept from "a" only, once
+            Some(same) => same != (operation == Operation::Difference),
+            None => contains_point(&b, piece.point_at(0.5)) == (operation == Operation::Intersection),
+        };
+        if keep {
+            kept.push(piece);
+        }
+    }
+    for piece in split_edges(&edges_b, splits_b) {
+        if boundary_direction(piece, &edges_a).is_some() {
+            continue;
+        }
+        let inside = contains_point(&a, piece.point_at(0.5));
+        match operation {
+            Operation::Union if !inside => kept.push(piece),
+            Operation::Intersection if inside => kept.push(piece),
+            Operation::Difference if inside => kept.push(Segment::new(piece.b, piece.a)),
+            _ => {}
+        }
+    }
+
+    link(kept)
+}
+
+/// Cuts every edge at its split points, given as parameters along the edge
+fn split_edges(edges: &[Segment], splits: Vec<Vec<(f32, Vector2)>>) -> Vec<Segment> {
+    let mut pieces = Vec::with_capacity(edges.len());
+    for (edge, mut splits) in edges.iter().zip(splits) {
+        splits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        let mut start = edge.a;
+        for (_, point) in splits.into_iter().chain(core::iter::once((1.0, edge.b))) {
+            if point != start {
+                pieces.push(Segment::new(start, point));
+                start = point;
+            }
+        }
+    }
+    pieces
+}
+
+/// Returns whether a piece lies on one of "edges", and if so whether it runs the same way
+fn boundary_direction(piece: Segment, edges: &[Segment]) -> Option<bool> {
+    let middle = piece.point_at(0.5);
+    edges.iter()
+        .find(|edge| Vector2::distance(edge.closest_point(middle), middle) <= EPSILON)
+        .map(|edge| Vector2::dot(piece.b - piece.a, edge.b - edge.a) > 0.0)
+}
+
+/// Follows pieces end to start until they close into outlines
+fn link(pieces: Vec<Segment>) -> Vec<Vec<Vector2>> {
+    let mut used = vec![false; pieces.len()];
+    let mut outlines = Vec::new();
+
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut outline = Vec::new();
+        let mut current = first;
+        let closed = loop {
+            used[current] = true;
+            outline.push(pieces[current].a);
+            let end = pieces[current].b;
+            if end == pieces[first].a {
+                break true;
+            }
+            match (0..pieces.len()).find(|&k| !used[k] && pieces[k].a == end) {
+                Some(next) => current = next,
+                None => break false,
+            }
+        };
+
+        if closed && outline.len() >= 3 {
+            outlines.push(outline);
+        }
+    }
+    outlines
+}
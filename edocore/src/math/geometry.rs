@@ -0,0 +1,660 @@
+//=============================================================================
+// geometry.rs
+// Shapes in three dimensional space and the tests between them
+//=============================================================================
+
+use alloc::vec::Vec;
+use crate::math::EPSILON;
+use crate::math::float::FloatMath;
+use crate::math::matrix::{ClipSpace, Matrix4};
+use crate::math::quaternion::Quaternion;
+use crate::math::random::Random;
+use crate::math::vector::{Vector3, Vector4};
+
+/// A half-line starting at "origin"
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray {
+    pub origin: Vector3,
+    /// Always has a magnitude of 1
+    pub direction: Vector3,
+}
+
+/// An infinite plane containing every point p where dot(normal, p) + distance = 0
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    pub normal: Vector3,
+    pub distance: f32,
+}
+
+/// An axis aligned bounding box
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+/// A sphere
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub center: Vector3,
+    pub radius: f32,
+}
+
+/// A box rotated into any orientation
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obb {
+    pub center: Vector3,
+    /// Half of the size along each of the axes
+    pub extents: Vector3,
+    /// Turns the x, y and z axes into the axes of the box
+    pub rotation: Quaternion,
+}
+
+/// A triangle, counter-clockwise when looking at its front
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+/// A view volume made of six planes with their normals pointing inward
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    /// Left, right, bottom, top, near and far
+    pub planes: [Plane; 6],
+}
+
+impl Ray {
+    /// Returns the point on the ray closest to "point"
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        let t = Vector3::dot(point - self.origin, self.direction).max(0.0);
+        self.point_at(t)
+    }
+
+    /// Returns the distance along the ray where it enters the box
+    pub fn intersect_aabb(self, aabb: Aabb) -> Option<f32> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+
+        // Slab test, one axis at a time
+        for axis in 0..3 {
+            let origin = self.origin.values[axis];
+            let direction = self.direction.values[axis];
+            let (min, max) = (aabb.min.values[axis], aabb.max.values[axis]);
+
+            if direction.abs() < EPSILON {
+                if origin < min || origin > max {
+                    return None;
+                }
+            } else {
+                let t1 = (min - origin) / direction;
+                let t2 = (max - origin) / direction;
+                t_min = t_min.max(t1.min(t2));
+                t_max = t_max.min(t1.max(t2));
+                if t_min > t_max {
+                    return None;
+                }
+            }
+        }
+
+        Some(t_min)
+    }
+
+    /// Returns the distance along the ray where it crosses the plane
+    pub fn intersect_plane(self, plane: Plane) -> Option<f32> {
+        let denominator = Vector3::dot(plane.normal, self.direction);
+        if denominator.abs() < EPSILON {
+            return None;
+        }
+
+        let t = -plane.signed_distance(self.origin) / denominator;
+        if t >= 0.0 { Some(t) } else { None }
+    }
+
+    /// Returns the distance along the ray where it enters the sphere
+    pub fn intersect_sphere(self, sphere: Sphere) -> Option<f32> {
+        let to_origin = self.origin - sphere.center;
+        let b = Vector3::dot(to_origin, self.direction);
+        let c = to_origin.square_magnitude() - sphere.radius * sphere.radius;
+
+        // Outside and pointing away
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        Some((-b - FloatMath::sqrt(discriminant)).max(0.0))
+    }
+
+    /// Returns the distance along the ray where it hits the triangle, both sides count
+    pub fn intersect_triangle(self, triangle: Triangle) -> Option<f32> {
+        // Moller-Trumbore
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = Vector3::cross(self.direction, edge2);
+        let det = Vector3::dot(edge1, p);
+        if det.abs() < EPSILON {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let s = self.origin - triangle.a;
+        let u = Vector3::dot(s, p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = Vector3::cross(s, edge1);
+        let v = Vector3::dot(self.direction, q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = Vector3::dot(edge2, q) * inv_det;
+        if t >= 0.0 { Some(t) } else { None }
+    }
+
+    /// Creates a new ray, the direction is normalized
+    pub fn new(origin: Vector3, direction: Vector3) -> Ray {
+        Ray { origin, direction: direction.normalized() }
+    }
+
+    /// Returns the point at distance "t" along the ray
+    pub fn point_at(self, t: f32) -> Vector3 {
+        self.origin + self.direction * t
+    }
+}
+
+impl Plane {
+    /// Returns the point on the plane closest to "point"
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Creates a plane through a point
+    pub fn from_point_normal(point: Vector3, normal: Vector3) -> Plane {
+        let normal = normal.normalized();
+        Plane { normal, distance: -Vector3::dot(normal, point) }
+    }
+
+    /// Creates a plane through three points, facing the side they are counter-clockwise on
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Plane {
+        Plane::from_point_normal(a, Vector3::cross(b - a, c - a))
+    }
+
+    /// Creates a plane from the coefficients of ax + by + cz + d = 0
+    pub fn from_vector4(coefficients: Vector4) -> Plane {
+        let normal = Vector3::new(coefficients.x, coefficients.y, coefficients.z);
+        let mag = normal.magnitude();
+        if mag > EPSILON {
+            Plane { normal: normal / mag, distance: coefficients.w / mag }
+        } else {
+            Plane { normal, distance: coefficients.w }
+        }
+    }
+
+    /// Creates a new plane, the normal is expected to have a magnitude of 1
+    pub fn new(normal: Vector3, distance: f32) -> Plane {
+        Plane { normal, distance }
+    }
+
+    /// Returns the distance to "point", negative when it is behind the plane
+    pub fn signed_distance(self, point: Vector3) -> f32 {
+        Vector3::dot(self.normal, point) + self.distance
+    }
+}
+
+impl Aabb {
+    /// Returns the center of the box
+    pub fn center(self) -> Vector3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the point in the box closest to "point"
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        Vector3::zip(Vector3::zip(point, self.min, f32::max), self.max, f32::min)
+    }
+
+    /// Returns true if the point is inside the box
+    pub fn contains_point(self, point: Vector3) -> bool {
+        (0..3).all(|i| point.values[i] >= self.min.values[i] && point.values[i] <= self.max.values[i])
+    }
+
+    /// Returns the box grown to contain "point"
+    pub fn encapsulate(self, point: Vector3) -> Aabb {
+        Aabb { min: Vector3::min(self.min, point), max: Vector3::max(self.max, point) }
+    }
+
+    /// Returns half of the size of the box
+    pub fn extents(self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Creates a box from its center and half of its size
+    pub fn from_center_extents(center: Vector3, extents: Vector3) -> Aabb {
+        Aabb { min: center - extents, max: center + extents }
+    }
+
+    /// Returns the smallest box containing every point, None if there are none
+    pub fn from_points(points: &[Vector3]) -> Option<Aabb> {
+        let (&first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Aabb { min: first, max: first }, |aabb, &point| aabb.encapsulate(point)))
+    }
+
+    /// Returns true if the boxes overlap
+    pub fn intersects_aabb(self, other: Aabb) -> bool {
+        (0..3).all(|i| self.min.values[i] <= other.max.values[i] && self.max.values[i] >= other.min.values[i])
+    }
+
+    /// Returns true if the box and the sphere overlap
+    pub fn intersects_sphere(self, sphere: Sphere) -> bool {
+        sphere.intersects_aabb(self)
+    }
+
+    /// Returns the smallest box containing both boxes
+    pub fn merge(self, other: Aabb) -> Aabb {
+        Aabb { min: Vector3::min(self.min, other.min), max: Vector3::max(self.max, other.max) }
+    }
+
+    /// Creates a new box, the corners may be given in any order
+    pub fn new(a: Vector3, b: Vector3) -> Aabb {
+        Aabb { min: Vector3::zip(a, b, f32::min), max: Vector3::zip(a, b, f32::max) }
+    }
+}
+
+impl Sphere {
+    /// Returns the point in the sphere closest to "point"
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        let to_point = point - self.center;
+        if to_point.square_magnitude() <= self.radius * self.radius {
+            point
+        } else {
+            self.center + to_point.normalized() * self.radius
+        }
+    }
+
+    /// Returns true if the point is inside the sphere
+    pub fn contains_point(self, point: Vector3) -> bool {
+        (point - self.center).square_magnitude() <= self.radius * self.radius
+    }
+
+    /// Returns the smallest sphere containing this sphere and "point"
+    pub fn encapsulate(self, point: Vector3) -> Sphere {
+        self.merge(Sphere::new(point, 0.0))
+    }
+
+    /// Returns the smallest sphere containing every point, None if there are none.
+    /// Uses Welzl's algorithm, in expected linear time
+    pub fn from_points(points: &[Vector3]) -> Option<Sphere> {
+        if points.is_empty() {
+            return None;
+        }
+
+        // A shuffle makes the expected time linear whatever the order of the input,
+        // the seed is fixed so that the same points always give the same sphere
+        let mut points = points.to_vec();
+        let mut random = Random::new(0);
+        for i in (1..points.len()).rev() {
+            points.swap(i, random.range_u32(i as u32 + 1) as usize);
+        }
+
+        let len = points.len();
+        Some(welzl(&mut points, len, &mut Vec::with_capacity(4)))
+    }
+
+    /// Returns true if the sphere and the box overlap
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// Returns true if the spheres overlap
+    pub fn intersects_sphere(self, other: Sphere) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).square_magnitude() <= radii * radii
+    }
+
+    /// Returns the smallest sphere containing both spheres
+    pub fn merge(self, other: Sphere) -> Sphere {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        if distance + other.radius <= self.radius {
+            return self;
+        }
+        if distance + self.radius <= other.radius {
+            return other;
+        }
+
+        let radius = (distance + self.radius + other.radius) * 0.5;
+        Sphere { center: self.center + offset * ((radius - self.radius) / distance), radius }
+    }
+
+    /// Creates a new sphere
+    pub fn new(center: Vector3, radius: f32) -> Sphere {
+        Sphere { center, radius }
+    }
+}
+
+impl Obb {
+    /// Returns the directions of the x, y and z axes of the box
+    pub fn axes(self) -> [Vector3; 3] {
+        [
+            self.rotation * Vector3::new(1.0, 0.0, 0.0),
+            self.rotation * Vector3::new(0.0, 1.0, 0.0),
+            self.rotation * Vector3::new(0.0, 0.0, 1.0),
+        ]
+    }
+
+    /// Returns true if the point is inside the box
+    pub fn contains_point(self, point: Vector3) -> bool {
+        let local = self.rotation.conjugate() * (point - self.center);
+        (0..3).all(|i| local.values[i].abs() <= self.extents.values[i] + EPSILON)
+    }
+
+    /// Returns the eight corners of the box
+    pub fn corners(self) -> [Vector3; 8] {
+        let [x, y, z] = self.axes();
+        core::array::from_fn(|i| {
+            let sign = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+            self.center + x * (sign(1) * self.extents.x) + y * (sign(2) * self.extents.y) + z * (sign(4) * self.extents.z)
+        })
+    }
+
+    /// Fits a box around the points, aligned with the directions in which they
+    /// spread the most (the principal components). None if there are no points.
+    /// The box is tight along its axes but not always the smallest one possible
+    pub fn from_points(points: &[Vector3]) -> Option<Obb> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let count = points.len() as f32;
+        let mean = points.iter().fold(Vector3::new(0.0, 0.0, 0.0), |sum, &p| sum + p) / count;
+        let mut covariance = [[0.0f32; 3]; 3];
+        for &point in points {
+            let d = point - mean;
+            for (row, values) in covariance.iter_mut().enumerate() {
+                for (col, value) in values.iter_mut().enumerate() {
+                    *value += d.values[row] * d.values[col] / count;
+                }
+            }
+        }
+
+        let [x, y, _] = symmetric_eigenvectors(covariance);
+        let z = Vector3::cross(x, y);
+        let rotation = Quaternion::from_matrix(Matrix4 {
+            values: [[x.x, y.x, z.x, 0.0],
+                [x.y, y.y, z.y, 0.0],
+                [x.z, y.z, z.z, 0.0],
+                [0.0, 0.0, 0.0, 1.0]]
+        });
+
+        // The extent of the points along each axis, measured from the mean
+        let axes = [x, y, z];
+        let local: Vec<Vector3> = points.iter().map(|&p| Vector3::from(axes.map(|axis| Vector3::dot(p - mean, axis)))).collect();
+        let bounds = Aabb::from_points(&local)?;
+        let offset = bounds.center();
+
+        Some(Obb {
+            center: mean + x * offset.x + y * offset.y + z * offset.z,
+            extents: bounds.extents(),
+            rotation,
+        })
+    }
+
+    /// Returns a box containing both boxes, fitted around their corners
+    pub fn merge(self, other: Obb) -> Obb {
+        let corners: Vec<Vector3> = self.corners().iter().chain(other.corners().iter()).copied().collect();
+        Obb::from_points(&corners).unwrap_or(self)
+    }
+
+    /// Creates a new box
+    pub fn new(center: Vector3, extents: Vector3, rotation: Quaternion) -> Obb {
+        Obb { center, extents, rotation }
+    }
+}
+
+impl Triangle {
+    /// Returns the barycentric coordinates of "point" projected onto the triangle
+    pub fn barycentric(self, point: Vector3) -> Vector3 {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = point - self.a;
+        let d00 = Vector3::dot(v0, v0);
+        let d01 = Vector3::dot(v0, v1);
+        let d11 = Vector3::dot(v1, v1);
+        let d20 = Vector3::dot(v2, v0);
+        let d21 = Vector3::dot(v2, v1);
+        let denominator = d00 * d11 - d01 * d01;
+
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Vector3::new(1.0 - v - w, v, w)
+    }
+
+    /// Returns the point on the triangle closest to "point"
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        // Checks the voronoi regions of the vertices and edges, see
+        // "Real-Time Collision Detection" by Christer Ericson
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = Vector3::dot(ab, ap);
+        let d2 = Vector3::dot(ac, ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = Vector3::dot(ab, bp);
+        let d4 = Vector3::dot(ac, bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = Vector3::dot(ab, cp);
+        let d6 = Vector3::dot(ac, cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = 1.0 / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    /// Creates a new triangle
+    pub fn new(a: Vector3, b: Vector3, c: Vector3) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    /// Returns the normal of the front face
+    pub fn normal(self) -> Vector3 {
+        Vector3::cross(self.b - self.a, self.c - self.a).normalized()
+    }
+}
+
+impl Frustum {
+    /// Returns true if the point is inside the frustum
+    pub fn contains_point(self, point: Vector3) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Extracts the frustum from a view-projection matrix
+    pub fn from_matrix(matrix: Matrix4, clip: ClipSpace) -> Frustum {
+        let rows = [matrix.get_row(0), matrix.get_row(1), matrix.get_row(2), matrix.get_row(3)];
+        let near = match clip {
+            ClipSpace::OpenGl => rows[3] + rows[2],
+            ClipSpace::Vulkan => rows[2],
+        };
+
+        Frustum {
+            planes: [
+                Plane::from_vector4(rows[3] + rows[0]),
+                Plane::from_vector4(rows[3] - rows[0]),
+                Plane::from_vector4(rows[3] + rows[1]),
+                Plane::from_vector4(rows[3] - rows[1]),
+                Plane::from_vector4(near),
+                Plane::from_vector4(rows[3] - rows[2]),
+            ]
+        }
+    }
+
+    /// Returns true if the box is at least partially inside the frustum.
+    /// Boxes near the corners may be reported as inside even when they are not
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            // The corner furthest along the normal
+            let corner = Vector3::from(core::array::from_fn(|i| {
+                if plane.normal.values[i] >= 0.0 { aabb.max.values[i] } else { aabb.min.values[i] }
+            }));
+            plane.signed_distance(corner) >= 0.0
+        })
+    }
+
+    /// Returns true if the sphere is at least partially inside the frustum.
+    /// Spheres near the corners may be reported as inside even when they are not
+    pub fn intersects_sphere(self, sphere: Sphere) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+}
+
+/// Returns the smallest sphere containing the first "end" points with every "support"
+/// point on its surface. Points found outside are moved to the front, which makes
+/// later calls find them early
+fn welzl(points: &mut [Vector3], end: usize, support: &mut Vec<Vector3>) -> Sphere {
+    let mut sphere = sphere_through(support);
+    if support.len() == 4 {
+        return sphere;
+    }
+
+    for i in 0..end {
+        let point = points[i];
+        // Rounding leaves points on the surface slightly outside
+        if Vector3::distance(point, sphere.center) <= sphere.radius + EPSILON * (1.0 + sphere.radius) {
+            continue;
+        }
+
+        support.push(point);
+        sphere = welzl(points, i, support);
+        support.pop();
+        points[..=i].rotate_right(1);
+    }
+    sphere
+}
+
+/// Returns the smallest sphere with up to four points on its surface. No points
+/// give a sphere that contains nothing
+fn sphere_through(support: &[Vector3]) -> Sphere {
+    match *support {
+        [] => Sphere::new(Vector3::new(0.0, 0.0, 0.0), -1.0),
+        [a] => Sphere::new(a, 0.0),
+        [a, b] => Sphere::new((a + b) * 0.5, Vector3::distance(a, b) * 0.5),
+        [a, b, c] => {
+            let (ab, ac) = (b - a, c - a);
+            let normal = Vector3::cross(ab, ac);
+            let denominator = 2.0 * normal.square_magnitude();
+            if denominator < EPSILON * EPSILON {
+                // Collinear, the two points furthest apart span the sphere
+                return [sphere_through(&[a, b]), sphere_through(&[a, c]), sphere_through(&[b, c])]
+                    .iter()
+                    .copied()
+                    .fold(Sphere::new(a, 0.0), |best, s| if s.radius > best.radius { s } else { best });
+            }
+
+            let offset = (Vector3::cross(normal, ab) * ac.square_magnitude() + Vector3::cross(ac, normal) * ab.square_magnitude())
+                / denominator;
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        [a, b, c, d] => {
+            let (ab, ac, ad) = (b - a, c - a, d - a);
+            let denominator = 2.0 * Vector3::dot(ab, Vector3::cross(ac, ad));
+            if denominator.abs() < EPSILON * EPSILON {
+                // Coplanar, the smallest circle of three points around the fourth
+                return [[a, b, c], [a, b, d], [a, c, d], [b, c, d]]
+                    .iter()
+                    .map(|points| sphere_through(points))
+                    .filter(|s| [a, b, c, d].iter().all(|&p| Vector3::distance(p, s.center) <= s.radius + EPSILON * (1.0 + s.radius)))
+                    .fold(None, |best: Option<Sphere>, s| match best {
+                        Some(best) if best.radius <= s.radius => Some(best),
+                        _ => Some(s),
+                    })
+                    .unwrap_or_else(|| sphere_through(&[a, b, c]));
+            }
+
+            let offset = (Vector3::cross(ac, ad) * ab.square_magnitude()
+                + Vector3::cross(ad, ab) * ac.square_magnitude()
+                + Vector3::cross(ab, ac) * ad.square_magnitude())
+                / denominator;
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        _ => unreachable!("a sphere is defined by at most four points"),
+    }
+}
+
+/// Returns the eigenvectors of a symmetric matrix, sorted from the largest
+/// eigenvalue down, using Jacobi rotations
+fn symmetric_eigenvectors(mut a: [[f32; 3]; 3]) -> [Vector3; 3] {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    for _ in 0..32 {
+        let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off_diagonal <= diagonal * f32::EPSILON * f32::EPSILON {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+
+            // The rotation in the pq plane that zeroes a[p][q]
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + FloatMath::sqrt(theta * theta + 1.0));
+            let c = 1.0 / FloatMath::sqrt(t * t + 1.0);
+            let s = t * c;
+
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(core::cmp::Ordering::Equal));
+    order.map(|i| Vector3::new(v[0][i], v[1][i], v[2][i]))
+}
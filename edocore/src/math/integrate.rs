@@ -0,0 +1,122 @@
+//=============================================================================
+// integrate.rs
+// Stepping positions and velocities forward in time
+//
+// Every stepper is plain arithmetic on its inputs, so the same state, timestep
+// and forces always give the same result. With `Fixed` vectors that holds on
+// every platform too.
+//=============================================================================
+
+use core::ops::{Add, Mul};
+use crate::math::scalar::{Float, Scalar};
+use crate::math::vector::Vector;
+
+/// The position and velocity of a moving body
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State<T, const N: usize> {
+    pub position: Vector<T, N>,
+    pub velocity: Vector<T, N>,
+}
+
+impl<T, const N: usize> State<T, N> {
+    /// Creates a new state
+    pub fn new(position: Vector<T, N>, velocity: Vector<T, N>) -> Self {
+        State { position, velocity }
+    }
+}
+
+/// Steps a body with semi-implicit (symplectic) Euler: the velocity is updated
+/// first and the position then moves with the new velocity. As cheap as explicit
+/// Euler but keeps orbits and springs from gaining energy.
+/// "acceleration" is given the position and velocity at the start of the step
+pub fn semi_implicit_euler<T, const N: usize, F>(state: State<T, N>, dt: T, acceleration: F) -> State<T, N>
+where
+    T: Float,
+    F: Fn(Vector<T, N>, Vector<T, N>) -> Vector<T, N>,
+{
+    let velocity = state.velocity + acceleration(state.position, state.velocity) * dt;
+    State { position: state.position + velocity * dt, velocity }
+}
+
+/// Steps a body with velocity Verlet, second order accurate and energy conserving
+/// for forces that only depend on the position, such as gravity and springs.
+/// "acceleration" is given the position at the start and at the end of the step
+pub fn velocity_verlet<T, const N: usize, F>(state: State<T, N>, dt: T, acceleration: F) -> State<T, N>
+where
+    T: Float,
+    F: Fn(Vector<T, N>) -> Vector<T, N>,
+{
+    let half_dt = dt / (T::ONE + T::ONE);
+    let start = acceleration(state.position);
+    let position = state.position + state.velocity * dt + start * (half_dt * dt);
+    let end = acceleration(position);
+    State { position, velocity: state.velocity + (start + end) * half_dt }
+}
+
+/// Steps the equation dy/dt = f(t, y) from "t" to "t" + "dt" with the classic
+/// fourth order Runge-Kutta method. "y" can be a scalar, a vector or a `State`,
+/// whose derivative is the state made of its velocity and acceleration
+pub fn rk4<T, Y, F>(y: Y, t: T, dt: T, f: F) -> Y
+where
+    T: Float,
+    Y: Copy + Add<Output = Y> + Mul<T, Output = Y>,
+    F: Fn(T, Y) -> Y,
+{
+    let two = T::ONE + T::ONE;
+    let half_dt = dt / two;
+
+    let k1 = f(t, y);
+    let k2 = f(t + half_dt, y + k1 * half_dt);
+    let k3 = f(t + half_dt, y + k2 * half_dt);
+    let k4 = f(t + dt, y + k3 * dt);
+    y + (k1 + k2 * two + k3 * two + k4) * (dt / (two + two + two))
+}
+
+/// Moves "current" toward "target" like a critically damped spring, reaching it in
+/// about "smooth_time" seconds without overshooting. "velocity" carries the motion
+/// between calls and should start at zero. Unlike `Vector::move_toward` the motion
+/// eases in and out, which suits cameras and UI
+pub fn smooth_damp<T: Float, const N: usize>(
+    current: Vector<T, N>,
+    target: Vector<T, N>,
+    velocity: &mut Vector<T, N>,
+    smooth_time: T,
+    dt: T,
+) -> Vector<T, N> {
+    let smooth_time = if smooth_time > T::EPSILON { smooth_time } else { T::EPSILON };
+    let omega = (T::ONE + T::ONE) / smooth_time;
+
+    // A polynomial approximation of exp(-omega * dt) that is exact enough here
+    // and gives the same bits everywhere
+    let x = omega * dt;
+    let decay = T::ONE / (T::ONE + x + T::from_f32(0.48) * x * x + T::from_f32(0.235) * x * x * x);
+
+    let change = current - target;
+    let temp = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - temp * omega) * decay;
+    let output = target + (change + temp) * decay;
+
+    // Stop at the target instead of passing it
+    if Vector::dot(target - current, output - target) > T::ZERO {
+        *velocity = Vector { values: [T::ZERO; N] };
+        return target;
+    }
+    output
+}
+
+impl<T: Scalar, const N: usize> Add for State<T, N> {
+    type Output = State<T, N>;
+
+    fn add(self, rhs: State<T, N>) -> Self::Output {
+        State { position: self.position + rhs.position, velocity: self.velocity + rhs.velocity }
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for State<T, N> {
+    type Output = State<T, N>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        State { position: self.position * rhs, velocity: self.velocity * rhs }
+    }
+}
@@ -0,0 +1,280 @@
+//=============================================================================
+// layout.rs
+// Byte views of the math types and GPU buffer layouts
+//
+// Vectors, matrices, quaternions and colors are `#[repr(C)]` arrays of their
+// components without padding, so slices of them can be uploaded as they are.
+// Uniform and storage buffers have stricter rules (std140 and std430), which
+// `UniformLayout` applies while writing the values out.
+//=============================================================================
+
+/// Re-exported for `impl_uniform_layout`, which must not rely on `Vec` being in scope
+#[doc(hidden)]
+pub use alloc::vec::Vec;
+use core::mem::{align_of, size_of, size_of_val};
+use crate::color::{Hsva, LinearRgba, Oklab, Srgba};
+use crate::math::fixed::Fixed;
+use crate::math::matrix::Matrix;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::Vector;
+
+/// Plain data: no padding, no pointers and every bit pattern is a valid value
+///
+/// # Safety
+/// Implementing this for a type that doesn't satisfy the above lets `cast_slice`
+/// create invalid values or read uninitialized padding
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+unsafe impl Pod for Fixed {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+unsafe impl<T: Pod, const N: usize> Pod for Vector<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for Matrix<T, N> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+unsafe impl Pod for LinearRgba {}
+unsafe impl Pod for Srgba {}
+unsafe impl Pod for Hsva {}
+unsafe impl Pod for Oklab {}
+
+/// Returns the bytes of a value in memory
+pub fn as_bytes<T: Pod>(value: &T) -> &[u8] {
+    // SAFETY: Pod types have no padding, so every byte is initialized
+    unsafe { core::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+/// Reinterprets a slice as a slice of another type, such as vertices as floats or bytes.
+/// Panics if the slice isn't aligned for "B" or its size isn't a multiple of the size of "B"
+pub fn cast_slice<A: Pod, B: Pod>(slice: &[A]) -> &[B] {
+    let bytes = size_of_val(slice);
+    assert!(size_of::<B>() > 0, "cannot cast into a zero sized type");
+    assert_eq!(bytes % size_of::<B>(), 0, "the slice size is not a multiple of the target size");
+    assert_eq!(slice.as_ptr() as usize % align_of::<B>(), 0, "the slice is not aligned for the target type");
+
+    // SAFETY: the size and alignment were checked and every bit pattern of B is valid
+    unsafe { core::slice::from_raw_parts(slice.as_ptr() as *const B, bytes / size_of::<B>()) }
+}
+
+/// The rules for placing values in a GPU buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BufferLayout {
+    /// Uniform buffers: arrays, matrix columns and structs are aligned to 16 bytes
+    Std140,
+    /// Storage buffers and push constants: like std140 without the 16 byte rounding
+    Std430,
+}
+
+/// A type that can be written into a GPU buffer.
+/// Structs implement it with `impl_uniform_layout!`
+pub trait UniformLayout {
+    /// Returns the alignment in bytes
+    fn alignment(layout: BufferLayout) -> usize;
+
+    /// Returns the size in bytes, including padding inside the value
+    fn size(layout: BufferLayout) -> usize;
+
+    /// Appends the value to "buffer", whose length is already a multiple of `alignment`
+    fn write(&self, layout: BufferLayout, buffer: &mut Vec<u8>);
+
+    /// Returns the value laid out in a buffer of its own
+    fn to_buffer(&self, layout: BufferLayout) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(Self::size(layout));
+        self.write(layout, &mut buffer);
+        buffer
+    }
+}
+
+/// Pads "buffer" with zeros to a multiple of "alignment"
+#[doc(hidden)]
+pub fn pad(buffer: &mut Vec<u8>, alignment: usize) {
+    buffer.resize(round_up(buffer.len(), alignment), 0);
+}
+
+/// Rounds "value" up to a multiple of "alignment"
+#[doc(hidden)]
+pub fn round_up(value: usize, alignment: usize) -> usize {
+    value.div_ceil(alignment) * alignment
+}
+
+/// Returns the alignment of arrays and structs in "layout" whose members have "alignment"
+#[doc(hidden)]
+pub fn aggregate_alignment(layout: BufferLayout, alignment: usize) -> usize {
+    match layout {
+        BufferLayout::Std140 => round_up(alignment, 16),
+        BufferLayout::Std430 => alignment,
+    }
+}
+
+macro_rules! impl_uniform_scalar {
+    ($($t:ty),*) => {$(
+        impl UniformLayout for $t {
+            fn alignment(_layout: BufferLayout) -> usize {
+                size_of::<$t>()
+            }
+
+            fn size(_layout: BufferLayout) -> usize {
+                size_of::<$t>()
+            }
+
+            fn write(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(as_bytes(self));
+            }
+        }
+    )*};
+}
+
+impl_uniform_scalar!(f32, f64, i32, u32);
+
+macro_rules! impl_uniform_vector {
+    ($($n:literal => $aligned:literal),*) => {$(
+        /// A GLSL vector, three component vectors are aligned like four component ones
+        impl<T: UniformLayout + Pod> UniformLayout for Vector<T, $n> {
+            fn alignment(layout: BufferLayout) -> usize {
+                T::size(layout) * $aligned
+            }
+
+            fn size(layout: BufferLayout) -> usize {
+                T::size(layout) * $n
+            }
+
+            fn write(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(as_bytes(self));
+            }
+        }
+    )*};
+}
+
+impl_uniform_vector!(2 => 2, 3 => 4, 4 => 4);
+
+macro_rules! impl_uniform_matrix {
+    ($($n:literal),*) => {$(
+        /// A GLSL matrix. GLSL stores matrices as columns, so the columns are written
+        /// and the shader sees the same matrix as the engine
+        impl<T: UniformLayout + Pod> UniformLayout for Matrix<T, $n> {
+            fn alignment(layout: BufferLayout) -> usize {
+                aggregate_alignment(layout, Vector::<T, $n>::alignment(layout))
+            }
+
+            fn size(layout: BufferLayout) -> usize {
+                Self::alignment(layout) * $n
+            }
+
+            fn write(&self, layout: BufferLayout, buffer: &mut Vec<u8>) {
+                let start = buffer.len();
+                for column in 0..$n {
+                    let column = Vector { values: self.values.map(|row| row[column]) };
+                    column.write(layout, buffer);
+                    pad(buffer, Self::alignment(layout));
+                }
+                debug_assert_eq!(buffer.len() - start, Self::size(layout));
+            }
+        }
+    )*};
+}
+
+impl_uniform_matrix!(2, 3, 4);
+
+/// An array, every element starts at a multiple of the array alignment
+impl<T: UniformLayout, const N: usize> UniformLayout for [T; N] {
+    fn alignment(layout: BufferLayout) -> usize {
+        aggregate_alignment(layout, T::alignment(layout))
+    }
+
+    fn size(layout: BufferLayout) -> usize {
+        round_up(T::size(layout), Self::alignment(layout)) * N
+    }
+
+    fn write(&self, layout: BufferLayout, buffer: &mut Vec<u8>) {
+        for element in self.iter() {
+            element.write(layout, buffer);
+            pad(buffer, Self::alignment(layout));
+        }
+    }
+}
+
+/// A rotation, written as a vec4 (or dvec4) of x, y, z and w
+impl<T: UniformLayout + Pod> UniformLayout for Quaternion<T> {
+    fn alignment(layout: BufferLayout) -> usize {
+        Vector::<T, 4>::alignment(layout)
+    }
+
+    fn size(layout: BufferLayout) -> usize {
+        Vector::<T, 4>::size(layout)
+    }
+
+    fn write(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(as_bytes(self));
+    }
+}
+
+/// A linear color, written as a vec4 of r, g, b and a
+impl UniformLayout for LinearRgba {
+    fn alignment(layout: BufferLayout) -> usize {
+        Vector::<f32, 4>::alignment(layout)
+    }
+
+    fn size(layout: BufferLayout) -> usize {
+        Vector::<f32, 4>::size(layout)
+    }
+
+    fn write(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(as_bytes(self));
+    }
+}
+
+/// Implements `UniformLayout` for a struct, listing its fields in the order of the
+/// shader declaration:
+///
+/// ```
+/// use edocore::impl_uniform_layout;
+/// use edocore::math::layout::{BufferLayout, UniformLayout};
+/// use edocore::math::vector::Vector3;
+///
+/// struct Light {
+///     position: Vector3,
+///     intensity: f32,
+/// }
+///
+/// impl_uniform_layout!(Light { position: Vector3, intensity: f32 });
+///
+/// assert_eq!(Light::size(BufferLayout::Std140), 16);
+/// ```
+#[macro_export]
+macro_rules! impl_uniform_layout {
+    ($name:ident { $($field:ident: $t:ty),* $(,)? }) => {
+        impl $crate::math::layout::UniformLayout for $name {
+            fn alignment(layout: $crate::math::layout::BufferLayout) -> usize {
+                let alignment = 1;
+                $(let alignment = alignment.max(<$t as $crate::math::layout::UniformLayout>::alignment(layout));)*
+                $crate::math::layout::aggregate_alignment(layout, alignment)
+            }
+
+            fn size(layout: $crate::math::layout::BufferLayout) -> usize {
+                let offset = 0;
+                $(
+                    let offset = $crate::math::layout::round_up(
+                        offset,
+                        <$t as $crate::math::layout::UniformLayout>::alignment(layout),
+                    ) + <$t as $crate::math::layout::UniformLayout>::size(layout);
+                )*
+                $crate::math::layout::round_up(offset, Self::alignment(layout))
+            }
+
+            fn write(&self, layout: $crate::math::layout::BufferLayout, buffer: &mut $crate::math::layout::Vec<u8>) {
+                $(
+                    $crate::math::layout::pad(buffer, <$t as $crate::math::layout::UniformLayout>::alignment(layout));
+                    $crate::math::layout::UniformLayout::write(&self.$field, layout, buffer);
+                )*
+                $crate::math::layout::pad(buffer, Self::alignment(layout));
+            }
+        }
+    };
+}
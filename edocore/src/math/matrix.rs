@@ -4,82 +4,497 @@
 // Created by Victor on 2019/11/01
 //=============================================================================
 
-use std::ops::Mul;
-use crate::math::vector::Vector4;
+use core::fmt::{Display, Formatter};
+use core::ops::Mul;
+use crate::math::angle::Radians;
+use crate::math::fixed::Fixed;
+use crate::math::quaternion::Quaternion;
+use crate::math::scalar::{Float, Real, Scalar};
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use crate::math::simd;
+use crate::math::vector::Vector;
 
-#[derive(Copy, Clone)]
-pub struct Matrix4 {
-    pub values: [[f32; 4]; 4],
+/// The depth range and vertical direction expected by a graphics API
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClipSpace {
+    /// Depth in [-1, 1] with y pointing up
+    OpenGl,
+    /// Depth in [0, 1] with y pointing down
+    Vulkan,
 }
 
-impl Matrix4 {
-    /// Returns a column of the matrix represented as a Vector4
-    pub fn get_column(self, index: usize) -> Vector4 {
-        Vector4 {
-            x: self.values[0][index],
-            y: self.values[1][index],
-            z: self.values[2][index],
-            w: self.values[3][index],
+/// The translation, rotation and scale of a transform matrix, see `Matrix::decompose`
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decomposition<T = f32> {
+    pub translation: Vector<T, 3>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vector<T, 3>,
+}
+
+/// Why a matrix could not be split into translation, rotation and scale
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecomposeError {
+    /// The bottom row is not (0, 0, 0, 1), as in a projection
+    Projective,
+    /// An axis is scaled to zero, so its direction is lost
+    Singular,
+    /// The axes are not perpendicular, the matrix skews space
+    Shear,
+    /// The matrix mirrors space, which a rotation and positive scale can't do.
+    /// Negating one axis of the scale gives a matrix that can be split
+    Reflection,
+}
+
+/// A square NxN matrix stored in row-major order.
+/// Vectors are treated as columns, so `a * b * v` applies `b` first
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix<T, const N: usize> {
+    pub values: [[T; N]; N],
+}
+
+/// A 2x2 matrix
+pub type Matrix2 = Matrix<f32, 2>;
+/// A 3x3 matrix
+pub type Matrix3 = Matrix<f32, 3>;
+/// A 4x4 matrix
+pub type Matrix4 = Matrix<f32, 4>;
+/// A 2x2 matrix of fixed-point numbers
+pub type FixedMatrix2 = Matrix<Fixed, 2>;
+/// A 3x3 matrix of fixed-point numbers
+pub type FixedMatrix3 = Matrix<Fixed, 3>;
+/// A 4x4 matrix of fixed-point numbers
+pub type FixedMatrix4 = Matrix<Fixed, 4>;
+/// A 2x2 matrix in double precision
+pub type DMatrix2 = Matrix<f64, 2>;
+/// A 3x3 matrix in double precision
+pub type DMatrix3 = Matrix<f64, 3>;
+/// A 4x4 matrix in double precision
+pub type DMatrix4 = Matrix<f64, 4>;
+
+impl<T: Scalar, const N: usize> Matrix<T, N> {
+    /// Returns a column of the matrix represented as a vector
+    pub fn get_column(self, index: usize) -> Vector<T, N> {
+        Vector::from(core::array::from_fn(|row| self.values[row][index]))
+    }
+
+    /// Returns a row of the matrix represented as a vector
+    pub fn get_row(self, index: usize) -> Vector<T, N> {
+        Vector::from(self.values[index])
+    }
+
+    /// Returns the identity matrix
+    pub fn identity() -> Self {
+        Matrix {
+            values: core::array::from_fn(|row| core::array::from_fn(|col| if row == col { T::ONE } else { T::ZERO })),
         }
     }
 
-    /// Returns a row of the matrix represented as a Vector4
-    pub fn get_row(self, index: usize) -> Vector4 {
-        Vector4 {
-            x: self.values[index][0],
-            y: self.values[index][1],
-            z: self.values[index][2],
-            w: self.values[index][3],
+    /// Converts the matrix to another size.
+    /// Shrinking keeps the upper-left part, growing fills the new part with the identity
+    pub fn resize<const M: usize>(self) -> Matrix<T, M> {
+        let mut res = Matrix::<T, M>::identity();
+        for (row, values) in res.values.iter_mut().enumerate().take(N) {
+            for (col, value) in values.iter_mut().enumerate().take(N) {
+                *value = self.values[row][col];
+            }
         }
+        res
     }
 
-    /// Creates a new 4x4 matrix using row vectors
-    pub fn new(row0: Vector4, row1: Vector4, row2: Vector4, row3: Vector4) -> Matrix4 {
-        Matrix4 {
-            values: [[row0.x, row0.y, row0.z, row0.w],
-                [row1.x, row1.y, row1.z, row1.w],
-                [row2.x, row2.y, row2.z, row2.w],
-                [row3.x, row3.y, row3.z, row3.w]]
+    /// Returns the transpose of the matrix
+    pub fn transpose(self) -> Self {
+        Matrix { values: core::array::from_fn(|row| core::array::from_fn(|col| self.values[col][row])) }
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N> {
+    /// Returns the determinant of the matrix
+    pub fn determinant(self) -> T {
+        let mut m = self.values;
+        let mut det = T::ONE;
+
+        for col in 0..N {
+            let pivot = Self::pivot_row(&m, col);
+            if m[pivot][col] == T::ZERO {
+                return T::ZERO;
+            }
+            if pivot != col {
+                m.swap(pivot, col);
+                det = -det;
+            }
+
+            det = det * m[col][col];
+            let pivot_values = m[col];
+            for row in m.iter_mut().skip(col + 1) {
+                let factor = row[col] / pivot_values[col];
+                for k in col..N {
+                    row[k] = row[k] - factor * pivot_values[k];
+                }
+            }
         }
+
+        det
+    }
+
+    /// Returns the inverse of the matrix, or None if the matrix is singular
+    pub fn inverse(self) -> Option<Self> {
+        let mut m = self.values;
+        let mut inv = Self::identity().values;
+
+        // Pivots smaller than this are treated as zero
+        let largest = m.iter().flatten().fold(T::ZERO, |acc, v| if v.abs() > acc { v.abs() } else { acc });
+        let tolerance = largest * T::MACHINE_EPSILON;
+
+        // Gauss-Jordan elimination with partial pivoting
+        for col in 0..N {
+            let pivot = Self::pivot_row(&m, col);
+            if m[pivot][col].abs() <= tolerance {
+                return None;
+            }
+            m.swap(pivot, col);
+            inv.swap(pivot, col);
+
+            let scale = T::ONE / m[col][col];
+            for k in 0..N {
+                m[col][k] = m[col][k] * scale;
+                inv[col][k] = inv[col][k] * scale;
+            }
+
+            for row in 0..N {
+                if row == col {
+                    continue;
+                }
+                let factor = m[row][col];
+                for k in 0..N {
+                    m[row][k] = m[row][k] - factor * m[col][k];
+                    inv[row][k] = inv[row][k] - factor * inv[col][k];
+                }
+            }
+        }
+
+        Some(Matrix { values: inv })
+    }
+
+    /// Finds the row at or below "col" with the largest value in that column
+    fn pivot_row(m: &[[T; N]; N], col: usize) -> usize {
+        let mut pivot = col;
+        for row in col + 1..N {
+            if m[row][col].abs() > m[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        pivot
     }
 }
 
-impl PartialEq for Matrix4 {
-    fn eq(&self, other: &Self) -> bool {
-        self.get_row(0) == other.get_row(0) &&
-            self.get_row(1) == other.get_row(1) &&
-            self.get_row(2) == other.get_row(2) &&
-            self.get_row(3) == other.get_row(3)
+impl<T: Copy> Matrix<T, 2> {
+    /// Creates a new 2x2 matrix using row vectors
+    pub fn new(row0: Vector<T, 2>, row1: Vector<T, 2>) -> Self {
+        Matrix { values: [row0.values, row1.values] }
     }
 }
 
-impl Mul<Matrix4> for Matrix4 {
-    type Output = Matrix4;
+impl<T: Copy> Matrix<T, 3> {
+    /// Creates a new 3x3 matrix using row vectors
+    pub fn new(row0: Vector<T, 3>, row1: Vector<T, 3>, row2: Vector<T, 3>) -> Self {
+        Matrix { values: [row0.values, row1.values, row2.values] }
+    }
+}
+
+impl<T: Copy> Matrix<T, 4> {
+    /// Creates a new 4x4 matrix using row vectors
+    pub fn new(row0: Vector<T, 4>, row1: Vector<T, 4>, row2: Vector<T, 4>, row3: Vector<T, 4>) -> Self {
+        Matrix { values: [row0.values, row1.values, row2.values, row3.values] }
+    }
+}
+
+impl<T: Real> Matrix<T, 2> {
+    /// Creates a matrix rotating counter-clockwise by "angle"
+    pub fn from_angle(angle: impl Into<Radians<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Matrix { values: [[cos, -sin], [sin, cos]] }
+    }
+
+    /// Creates a scaling matrix
+    pub fn from_scale(scale: Vector<T, 2>) -> Self {
+        Matrix { values: [[scale.x, T::ZERO], [T::ZERO, scale.y]] }
+    }
+}
+
+impl<T: Real> Matrix<T, 3> {
+    /// Creates a 2D transform rotating counter-clockwise by "angle"
+    pub fn from_angle(angle: impl Into<Radians<T>>) -> Self {
+        Matrix::<T, 2>::from_angle(angle).resize()
+    }
+
+    /// Creates a rotation matrix
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        rotation.to_matrix().resize()
+    }
+
+    /// Creates a 2D scaling transform
+    pub fn from_scale(scale: Vector<T, 2>) -> Self {
+        Matrix::<T, 2>::from_scale(scale).resize()
+    }
 
-    fn mul(self, rhs: Matrix4) -> Self::Output {
-        let mut res = Matrix4 { values: [[0.0; 4]; 4] };
+    /// Creates a 2D translation transform
+    pub fn from_translation(translation: Vector<T, 2>) -> Self {
+        Matrix {
+            values: [[T::ONE, T::ZERO, translation.x],
+                [T::ZERO, T::ONE, translation.y],
+                [T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
 
-        // This makes me cry
-        res.values[0][0] = self.values[0][0] * rhs.values[0][0] + self.values[0][1] * rhs.values[1][0] + self.values[0][2] * rhs.values[2][0] + self.values[0][3] * rhs.values[3][0];
-        res.values[0][1] = self.values[0][0] * rhs.values[0][1] + self.values[0][1] * rhs.values[1][1] + self.values[0][2] * rhs.values[2][1] + self.values[0][3] * rhs.values[3][1];
-        res.values[0][2] = self.values[0][0] * rhs.values[0][2] + self.values[0][1] * rhs.values[1][2] + self.values[0][2] * rhs.values[2][2] + self.values[0][3] * rhs.values[3][2];
-        res.values[0][3] = self.values[0][0] * rhs.values[0][3] + self.values[0][1] * rhs.values[1][3] + self.values[0][2] * rhs.values[2][3] + self.values[0][3] * rhs.values[3][3];
+    /// Transforms a 2D direction, ignoring the translation of the matrix
+    pub fn transform_direction(self, direction: Vector<T, 2>) -> Vector<T, 2> {
+        (self * direction.extend(T::ZERO)).truncate()
+    }
 
-        res.values[1][0] = self.values[1][0] * rhs.values[0][0] + self.values[1][1] * rhs.values[1][0] + self.values[1][2] * rhs.values[2][0] + self.values[1][3] * rhs.values[3][0];
-        res.values[1][1] = self.values[1][0] * rhs.values[0][1] + self.values[1][1] * rhs.values[1][1] + self.values[1][2] * rhs.values[2][1] + self.values[1][3] * rhs.values[3][1];
-        res.values[1][2] = self.values[1][0] * rhs.values[0][2] + self.values[1][1] * rhs.values[1][2] + self.values[1][2] * rhs.values[2][2] + self.values[1][3] * rhs.values[3][2];
-        res.values[1][3] = self.values[1][0] * rhs.values[0][3] + self.values[1][1] * rhs.values[1][3] + self.values[1][2] * rhs.values[2][3] + self.values[1][3] * rhs.values[3][3];
+    /// Transforms a 2D point
+    pub fn transform_point(self, point: Vector<T, 2>) -> Vector<T, 2> {
+        (self * point.extend(T::ONE)).truncate()
+    }
+}
 
-        res.values[2][0] = self.values[2][0] * rhs.values[0][0] + self.values[2][1] * rhs.values[1][0] + self.values[2][2] * rhs.values[2][0] + self.values[2][3] * rhs.values[3][0];
-        res.values[2][1] = self.values[2][0] * rhs.values[0][1] + self.values[2][1] * rhs.values[1][1] + self.values[2][2] * rhs.values[2][1] + self.values[2][3] * rhs.values[3][1];
-        res.values[2][2] = self.values[2][0] * rhs.values[0][2] + self.values[2][1] * rhs.values[1][2] + self.values[2][2] * rhs.values[2][2] + self.values[2][3] * rhs.values[3][2];
-        res.values[2][3] = self.values[2][0] * rhs.values[0][3] + self.values[2][1] * rhs.values[1][3] + self.values[2][2] * rhs.values[2][3] + self.values[2][3] * rhs.values[3][3];
+impl<T: Real> Matrix<T, 4> {
+    /// Splits a matrix made by `trs` back into its parts. Fails instead of
+    /// returning wrong parts when the matrix shears, mirrors or projects
+    pub fn decompose(self) -> Result<Decomposition<T>, DecomposeError> {
+        let m = self.values;
+        if m[3][0].abs() > T::EPSILON || m[3][1].abs() > T::EPSILON || m[3][2].abs() > T::EPSILON
+            || (m[3][3] - T::ONE).abs() > T::EPSILON {
+            return Err(DecomposeError::Projective);
+        }
 
-        res.values[3][0] = self.values[3][0] * rhs.values[0][0] + self.values[3][1] * rhs.values[1][0] + self.values[3][2] * rhs.values[2][0] + self.values[3][3] * rhs.values[3][0];
-        res.values[3][1] = self.values[3][0] * rhs.values[0][1] + self.values[3][1] * rhs.values[1][1] + self.values[3][2] * rhs.values[2][1] + self.values[3][3] * rhs.values[3][1];
-        res.values[3][2] = self.values[3][0] * rhs.values[0][2] + self.values[3][1] * rhs.values[1][2] + self.values[3][2] * rhs.values[2][2] + self.values[3][3] * rhs.values[3][2];
-        res.values[3][3] = self.values[3][0] * rhs.values[0][3] + self.values[3][1] * rhs.values[1][3] + self.values[3][2] * rhs.values[2][3] + self.values[3][3] * rhs.values[3][3];
+        let axes: [Vector<T, 3>; 3] = core::array::from_fn(|col| self.get_column(col).truncate());
+        let scale = Vector::from(axes.map(Vector::magnitude));
+        if scale.values.iter().any(|&length| length < T::EPSILON) {
+            return Err(DecomposeError::Singular);
+        }
 
+        let [x, y, z] = axes.map(Vector::normalized);
+        if Vector::dot(x, y).abs() > T::EPSILON || Vector::dot(y, z).abs() > T::EPSILON || Vector::dot(z, x).abs() > T::EPSILON {
+            return Err(DecomposeError::Shear);
+        }
+        if Vector::dot(Vector::cross(x, y), z) < T::ZERO {
+            return Err(DecomposeError::Reflection);
+        }
+
+        let rotation = Matrix {
+            values: [[x.x, y.x, z.x, T::ZERO],
+                [x.y, y.y, z.y, T::ZERO],
+                [x.z, y.z, z.z, T::ZERO],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        };
+
+        Ok(Decomposition {
+            translation: self.get_column(3).truncate(),
+            rotation: Quaternion::from_matrix(rotation),
+            scale,
+        })
+    }
+
+    /// Creates a rotation matrix
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        rotation.to_matrix()
+    }
+
+    /// Creates a scaling matrix
+    pub fn from_scale(scale: Vector<T, 3>) -> Self {
+        Matrix {
+            values: [[scale.x, T::ZERO, T::ZERO, T::ZERO],
+                [T::ZERO, scale.y, T::ZERO, T::ZERO],
+                [T::ZERO, T::ZERO, scale.z, T::ZERO],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
+
+    /// Creates a translation matrix
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        Matrix {
+            values: [[T::ONE, T::ZERO, T::ZERO, translation.x],
+                [T::ZERO, T::ONE, T::ZERO, translation.y],
+                [T::ZERO, T::ZERO, T::ONE, translation.z],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
+
+    /// Creates a view matrix for a camera at "eye" looking toward "target".
+    /// The camera looks down the positive z axis in view space
+    pub fn look_at(eye: Vector<T, 3>, target: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let forward = (target - eye).normalized();
+        let right = Vector::cross(up, forward).normalized();
+        let up = Vector::cross(forward, right);
+
+        Matrix {
+            values: [[right.x, right.y, right.z, -Vector::dot(right, eye)],
+                [up.x, up.y, up.z, -Vector::dot(up, eye)],
+                [forward.x, forward.y, forward.z, -Vector::dot(forward, eye)],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
+
+    /// Creates an orthographic projection for a view space looking down positive z
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let two = T::ONE + T::ONE;
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+        let y_sign = if clip == ClipSpace::Vulkan { -T::ONE } else { T::ONE };
+
+        let (z_scale, z_offset) = match clip {
+            ClipSpace::OpenGl => (two / depth, -(far + near) / depth),
+            ClipSpace::Vulkan => (T::ONE / depth, -near / depth),
+        };
+
+        Matrix {
+            values: [[two / width, T::ZERO, T::ZERO, -(right + left) / width],
+                [T::ZERO, y_sign * two / height, T::ZERO, -y_sign * (top + bottom) / height],
+                [T::ZERO, T::ZERO, z_scale, z_offset],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
+
+    /// Creates a perspective projection for a view space looking down positive z.
+    /// "fov" is the vertical field of view
+    pub fn perspective(fov: impl Into<Radians<T>>, aspect: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let two = T::ONE + T::ONE;
+        let f = T::ONE / (fov.into() / two).tan();
+        let depth = far - near;
+        let y_scale = if clip == ClipSpace::Vulkan { -f } else { f };
+
+        let (z_scale, z_offset) = match clip {
+            ClipSpace::OpenGl => ((far + near) / depth, -two * far * near / depth),
+            ClipSpace::Vulkan => (far / depth, -far * near / depth),
+        };
+
+        Matrix {
+            values: [[f / aspect, T::ZERO, T::ZERO, T::ZERO],
+                [T::ZERO, y_scale, T::ZERO, T::ZERO],
+                [T::ZERO, T::ZERO, z_scale, z_offset],
+                [T::ZERO, T::ZERO, T::ONE, T::ZERO]]
+        }
+    }
+
+    /// Transforms a direction, ignoring the translation of the matrix
+    pub fn transform_direction(self, direction: Vector<T, 3>) -> Vector<T, 3> {
+        (self * direction.extend(T::ZERO)).truncate()
+    }
+
+    /// Transforms a point, dividing by w for projection matrices
+    pub fn transform_point(self, point: Vector<T, 3>) -> Vector<T, 3> {
+        let v = self * point.extend(T::ONE);
+        if v.w.abs() > T::EPSILON && v.w != T::ONE {
+            v.truncate() / v.w
+        } else {
+            v.truncate()
+        }
+    }
+
+    /// Creates a matrix that scales, then rotates, then translates
+    pub fn trs(translation: Vector<T, 3>, rotation: Quaternion<T>, scale: Vector<T, 3>) -> Self {
+        let mut res = rotation.to_matrix();
+        for row in 0..3 {
+            res.values[row][0] = res.values[row][0] * scale.x;
+            res.values[row][1] = res.values[row][1] * scale.y;
+            res.values[row][2] = res.values[row][2] * scale.z;
+        }
+        res.values[0][3] = translation.x;
+        res.values[1][3] = translation.y;
+        res.values[2][3] = translation.z;
         res
     }
+
+    /// Returns the matrix used to transform normals, the inverse-transpose
+    /// of the upper 3x3 part. None if the matrix is singular
+    pub fn normal_matrix(self) -> Option<Matrix<T, 3>> {
+        Matrix::<T, 3>::from(self).inverse().map(Matrix::transpose)
+    }
+}
+
+impl<T: Real> Decomposition<T> {
+    /// Builds the matrix back from its parts, undoing `Matrix::decompose`
+    pub fn recompose(self) -> Matrix<T, 4> {
+        Matrix::trs(self.translation, self.rotation, self.scale)
+    }
+}
+
+impl Display for DecomposeError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            DecomposeError::Projective => write!(f, "the matrix has a projection"),
+            DecomposeError::Singular => write!(f, "the matrix scales an axis to zero"),
+            DecomposeError::Shear => write!(f, "the matrix has a shear"),
+            DecomposeError::Reflection => write!(f, "the matrix has a reflection"),
+        }
+    }
+}
+
+impl core::error::Error for DecomposeError {}
+
+impl<const N: usize> Matrix<f64, N> {
+    /// Converts into a single precision matrix, for rendering
+    pub fn to_f32(self) -> Matrix<f32, N> {
+        Matrix { values: self.values.map(|row| row.map(|a| a as f32)) }
+    }
+}
+
+/// Widens a matrix to double precision, exactly
+impl<const N: usize> From<Matrix<f32, N>> for Matrix<f64, N> {
+    fn from(m: Matrix<f32, N>) -> Self {
+        Matrix { values: m.values.map(|row| row.map(f64::from)) }
+    }
+}
+
+macro_rules! impl_resize {
+    ($($from:expr => $to:expr),*) => {$(
+        impl<T: Scalar> From<Matrix<T, $from>> for Matrix<T, $to> {
+            fn from(m: Matrix<T, $from>) -> Self {
+                m.resize()
+            }
+        }
+    )*};
+}
+
+impl_resize!(2 => 3, 2 => 4, 3 => 2, 3 => 4, 4 => 2, 4 => 3);
+
+// Operator implementations
+impl<T: Scalar, const N: usize> Mul<Matrix<T, N>> for Matrix<T, N> {
+    type Output = Matrix<T, N>;
+
+    fn mul(self, rhs: Matrix<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::matrix4_mul(self.values, rhs.values) {
+                return Matrix { values };
+            }
+        }
+
+        let columns: [Vector<T, N>; N] = core::array::from_fn(|col| rhs.get_column(col));
+        Matrix {
+            values: core::array::from_fn(|row| {
+                let lhs_row = self.get_row(row);
+                core::array::from_fn(|col| Vector::dot(lhs_row, columns[col]))
+            }),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<Vector<T, N>> for Matrix<T, N> {
+    type Output = Vector<T, N>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::matrix4_transform(self.values, rhs.values) {
+                return Vector::from(values);
+            }
+        }
+
+        Vector::from(core::array::from_fn(|row| Vector::dot(self.get_row(row), rhs)))
+    }
 }
@@ -1,7 +1,28 @@
 ﻿pub mod vector;
+pub mod angle;
+pub mod approx;
+pub mod curve;
+pub mod fixed;
+pub(crate) mod float;
+pub mod geom2d;
+pub mod geometry;
+pub mod integrate;
+pub mod layout;
 pub mod matrix;
+pub mod noise;
+pub mod quaternion;
+pub mod random;
+pub mod scalar;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod transform;
+pub mod voxel;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod swizzle;
 
-const EPSILON: f32 = 0.00001;
+/// Tolerance used when a float is considered to be zero or equal to another
+pub const EPSILON: f32 = 0.00001;
 
 /// Clamps a float value between [min, max]
 pub fn clamp(value: f32, min: f32, max: f32) -> f32 {
@@ -0,0 +1,541 @@
+//=============================================================================
+// noise.rs
+// Seeded coherent noise for procedural generation
+//
+// Only additions, multiplications, divisions, floor and sqrt are used, all of
+// which IEEE 754 rounds exactly, so a seed gives the same values everywhere.
+//=============================================================================
+
+use crate::math::float::FloatMath;
+use crate::math::vector::{IVector3, Vector, Vector3};
+
+/// Radius squared of the area an OpenSimplex lattice point contributes to
+const RADIUS_SQUARED_2D: f32 = 2.0 / 3.0;
+const RADIUS_SQUARED_3D: f32 = 0.75;
+const RADIUS_SQUARED_4D: f32 = 0.8;
+/// Multipliers that bring OpenSimplex noise into about [-1, 1]
+const SCALE_2D: f32 = 16.0;
+const SCALE_3D: f32 = 10.5;
+const SCALE_4D: f32 = 5.0;
+/// The first of the 24 evenly spaced 2D gradients, the others are quarter turns of these
+const GRADIENTS_2D: [[f32; 2]; 6] = [
+    [0.991_444_9, 0.130_526_19],
+    [0.923_879_5, 0.382_683_43],
+    [0.793_353_3, 0.608_761_4],
+    [0.608_761_4, 0.793_353_3],
+    [0.382_683_43, 0.923_879_5],
+    [0.130_526_19, 0.991_444_9],
+];
+/// Shift between fractal octaves so they don't line up at the origin
+const OCTAVE_OFFSET: f32 = 71.37;
+/// Shift between the samples that warp each axis
+const WARP_OFFSET: f32 = 113.59;
+
+/// A function that returns a smoothly varying value for every point
+pub trait Noise<const N: usize> {
+    /// Returns the value at "point"
+    fn get(&self, point: Vector<f32, N>) -> f32;
+}
+
+/// Classic gradient noise on a square grid, in [-1, 1]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Perlin {
+    pub seed: u32,
+}
+
+/// OpenSimplex2S gradient noise in 2, 3 and 4 dimensions, in about [-1, 1].
+/// Smoother than `Perlin` and without its grid aligned artifacts. 2D and 4D use
+/// the simplex lattice, 3D a rotated body-centered cubic lattice
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpenSimplex {
+    pub seed: u32,
+}
+
+/// Which distance `Worley` noise returns
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CellValue {
+    /// Distance to the closest feature point, round cells
+    Nearest,
+    /// Distance to the second closest feature point
+    SecondNearest,
+    /// Difference of the two, zero along the cell borders
+    Difference,
+}
+
+/// Cellular noise, the distance to random feature points with one in every
+/// grid cell. The values are distances, mostly in [0, 1]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Worley {
+    pub seed: u32,
+    pub value: CellValue,
+}
+
+/// How the octaves of a `Fractal` are combined
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FractalKind {
+    /// Fractal Brownian motion, the plain sum of the octaves
+    Fbm,
+    /// Sharp ridges where the source crosses zero, like mountain ranges
+    Ridged,
+    /// Rounded bumps where the source crosses zero, like clouds
+    Billow,
+}
+
+/// Sums several octaves of a source at increasing frequencies and decreasing
+/// amplitudes. Sources in [-1, 1] give values in [-1, 1]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fractal<S> {
+    pub source: S,
+    pub kind: FractalKind,
+    pub octaves: u32,
+    /// Frequency multiplier between octaves
+    pub lacunarity: f32,
+    /// Amplitude multiplier between octaves
+    pub persistence: f32,
+}
+
+/// Samples a source at points displaced by another noise
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainWarp<S, W> {
+    pub source: S,
+    pub warp: W,
+    /// Largest distance a point is moved
+    pub strength: f32,
+}
+
+impl Perlin {
+    /// Creates a new noise
+    pub fn new(seed: u32) -> Perlin {
+        Perlin { seed }
+    }
+}
+
+impl<const N: usize> Noise<N> for Perlin {
+    fn get(&self, point: Vector<f32, N>) -> f32 {
+        let cell = point.values.map(FloatMath::floor);
+        let offset = point - Vector::from(cell);
+        let fade = offset.values.map(quintic);
+
+        // Blend the gradients of all 2^N corners of the cell
+        let mut sum = 0.0;
+        for corner in 0..1usize << N {
+            let mut lattice = [0; N];
+            let mut distance = offset.values;
+            let mut weight = 1.0;
+            for (axis, value) in distance.iter_mut().enumerate() {
+                let bit = (corner >> axis) & 1;
+                lattice[axis] = cell[axis] as i32 + bit as i32;
+                *value -= bit as f32;
+                weight *= if bit == 1 { fade[axis] } else { 1.0 - fade[axis] };
+            }
+            sum += weight * gradient_dot(hash(self.seed, &lattice), &distance);
+        }
+
+        // Half the diagonal of a cell times the length of the gradients
+        let bound = FloatMath::sqrt(N as f32 * gradient_length_squared(N)) / 2.0;
+        sum / bound
+    }
+}
+
+impl OpenSimplex {
+    /// Creates a new noise
+    pub fn new(seed: u32) -> OpenSimplex {
+        OpenSimplex { seed }
+    }
+}
+
+impl Noise<2> for OpenSimplex {
+    fn get(&self, point: Vector<f32, 2>) -> f32 {
+        skewed_lattice_sum(self.seed, point.values, RADIUS_SQUARED_2D, gradient_2d) * SCALE_2D
+    }
+}
+
+impl Noise<3> for OpenSimplex {
+    fn get(&self, point: Vector<f32, 3>) -> f32 {
+        // Rotate so the cubic axes of the lattice point along the main diagonal,
+        // keeping them out of xy slices
+        let r = (point.x + point.y + point.z) * (2.0 / 3.0);
+        let rotated = point.map(|v| r - v);
+
+        // The body-centered cubic lattice is two cubic grids, the second shifted by
+        // half a cell. Within the radius only the corners of the cell around the point count
+        let mut sum = 0.0;
+        for grid in 0..2 {
+            let shift = grid as f32 * 0.5;
+            let cell = rotated.values.map(|v| FloatMath::floor(v - shift));
+            for corner in 0..8 {
+                let mut lattice = [0; 3];
+                let mut distance = rotated.values;
+                for axis in 0..3 {
+                    let c = cell[axis] + ((corner >> axis) & 1) as f32;
+                    // Doubled coordinates give both grids distinct hashes
+                    lattice[axis] = c as i32 * 2 + grid;
+                    distance[axis] -= c + shift;
+                }
+                sum += kernel(RADIUS_SQUARED_3D, &distance) * gradient_3d(hash(self.seed, &lattice), &distance);
+            }
+        }
+
+        sum * SCALE_3D
+    }
+}
+
+impl Noise<4> for OpenSimplex {
+    fn get(&self, point: Vector<f32, 4>) -> f32 {
+        skewed_lattice_sum(self.seed, point.values, RADIUS_SQUARED_4D, gradient_4d) * SCALE_4D
+    }
+}
+
+impl Worley {
+    /// Creates a new noise returning the distance to the closest feature point
+    pub fn new(seed: u32) -> Worley {
+        Worley { seed, value: CellValue::Nearest }
+    }
+}
+
+impl<const N: usize> Noise<N> for Worley {
+    fn get(&self, point: Vector<f32, N>) -> f32 {
+        let cell = point.values.map(FloatMath::floor);
+        let mut nearest = f32::MAX;
+        let mut second = f32::MAX;
+
+        // Visit the 3^N cells around the point
+        let mut neighbour = [-1; N];
+        loop {
+            let mut lattice = [0; N];
+            for ((l, &c), &n) in lattice.iter_mut().zip(cell.iter()).zip(neighbour.iter()) {
+                *l = c as i32 + n;
+            }
+
+            let mut h = hash(self.seed, &lattice);
+            let mut distance = 0.0;
+            for (&l, &v) in lattice.iter().zip(point.values.iter()) {
+                h = mix(h);
+                let d = l as f32 + unit_float(h) - v;
+                distance += d * d;
+            }
+
+            if distance < nearest {
+                second = nearest;
+                nearest = distance;
+            } else if distance < second {
+                second = distance;
+            }
+
+            if !next_offset(&mut neighbour, -1, 1) {
+                break;
+            }
+        }
+
+        match self.value {
+            CellValue::Nearest => FloatMath::sqrt(nearest),
+            CellValue::SecondNearest => FloatMath::sqrt(second),
+            CellValue::Difference => FloatMath::sqrt(second) - FloatMath::sqrt(nearest),
+        }
+    }
+}
+
+impl<S> Fractal<S> {
+    /// Creates a fractal of "kind", doubling the frequency and halving the amplitude every octave
+    pub fn new(source: S, kind: FractalKind, octaves: u32) -> Fractal<S> {
+        Fractal { source, kind, octaves, lacunarity: 2.0, persistence: 0.5 }
+    }
+
+    /// Creates a fractal Brownian motion, see `FractalKind::Fbm`
+    pub fn fbm(source: S, octaves: u32) -> Fractal<S> {
+        Fractal::new(source, FractalKind::Fbm, octaves)
+    }
+
+    /// Creates a ridged fractal, see `FractalKind::Ridged`
+    pub fn ridged(source: S, octaves: u32) -> Fractal<S> {
+        Fractal::new(source, FractalKind::Ridged, octaves)
+    }
+
+    /// Creates a billowy fractal, see `FractalKind::Billow`
+    pub fn billow(source: S, octaves: u32) -> Fractal<S> {
+        Fractal::new(source, FractalKind::Billow, octaves)
+    }
+}
+
+impl<S: Noise<N>, const N: usize> Noise<N> for Fractal<S> {
+    fn get(&self, point: Vector<f32, N>) -> f32 {
+        let mut sum = 0.0;
+        let mut total = 0.0;
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+
+        for octave in 0..self.octaves {
+            let shift = octave as f32 * OCTAVE_OFFSET;
+            let value = self.source.get((point * frequency).map(|v| v + shift));
+            sum += amplitude * match self.kind {
+                FractalKind::Fbm => value,
+                FractalKind::Ridged => {
+                    let ridge = 1.0 - value.abs();
+                    ridge * ridge * 2.0 - 1.0
+                }
+                FractalKind::Billow => value.abs() * 2.0 - 1.0,
+            };
+
+            total += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        if total > 0.0 {
+            sum / total
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<S, W> DomainWarp<S, W> {
+    /// Creates a new warp
+    pub fn new(source: S, warp: W, strength: f32) -> DomainWarp<S, W> {
+        DomainWarp { source, warp, strength }
+    }
+}
+
+impl<S: Noise<N>, W: Noise<N>, const N: usize> Noise<N> for DomainWarp<S, W> {
+    fn get(&self, point: Vector<f32, N>) -> f32 {
+        // Every axis is moved by its own, offset sample of the warp
+        let mut warped = point;
+        for (axis, value) in warped.values.iter_mut().enumerate() {
+            let shift = (axis + 1) as f32 * WARP_OFFSET;
+            *value += self.warp.get(point.map(|v| v + shift)) * self.strength;
+        }
+
+        self.source.get(warped)
+    }
+}
+
+/// Fills "output" with the noise at every integer coordinate in [min, max) scaled by
+/// "frequency". Values are ordered x first, then y, then z
+pub fn fill_region<S: Noise<3> + ?Sized>(noise: &S, min: IVector3, max: IVector3, frequency: f32, output: &mut [f32]) {
+    let size = (max - min).map(|v| v.max(0) as usize);
+    assert_eq!(output.len(), size.x * size.y * size.z, "output does not match the size of the region");
+
+    let mut values = output.iter_mut();
+    for z in min.z..max.z {
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let point = Vector3::new(x as f32, y as f32, z as f32) * frequency;
+                *values.next().unwrap() = noise.get(point);
+            }
+        }
+    }
+}
+
+/// Sums the contributions of the simplex lattice points around "point", the
+/// integer grid skewed along its main diagonal. Used by 2D and 4D OpenSimplex
+fn skewed_lattice_sum<const N: usize>(seed: u32, point: [f32; N], radius_squared: f32, gradient: fn(u32, &[f32; N]) -> f32) -> f32 {
+    let n = N as f32;
+    let skew = (FloatMath::sqrt(n + 1.0) - 1.0) / n;
+    let unskew = (1.0 - 1.0 / FloatMath::sqrt(n + 1.0)) / n;
+
+    let skewed_sum = point.iter().sum::<f32>() * skew;
+    let cell = point.map(|v| FloatMath::floor(v + skewed_sum));
+    let mut fraction = [0.0; N];
+    for ((f, &p), &c) in fraction.iter_mut().zip(point.iter()).zip(cell.iter()) {
+        *f = p + skewed_sum - c;
+    }
+
+    let mut search = LatticeSearch {
+        seed,
+        point,
+        cell,
+        fraction,
+        radius_squared,
+        unskew,
+        squash: unskew * (2.0 - n * unskew),
+        gradient,
+        lattice: [0; N],
+        sum: 0.0,
+    };
+    search.visit(N, 0.0, 0.0);
+    search.sum
+}
+
+/// Depth first walk over the lattice points one cell around a skewed point,
+/// last axis first. With "u" the skewed offset from the point to a lattice
+/// point, the unskewed distance squared is |u|² - squash·(Σu)², so a partly
+/// chosen offset is dropped as soon as no choice of the free axes can bring it
+/// into the radius. Only the few points that can contribute reach the kernel
+struct LatticeSearch<const N: usize> {
+    seed: u32,
+    point: [f32; N],
+    cell: [f32; N],
+    fraction: [f32; N],
+    radius_squared: f32,
+    unskew: f32,
+    squash: f32,
+    gradient: fn(u32, &[f32; N]) -> f32,
+    lattice: [i32; N],
+    sum: f32,
+}
+
+impl<const N: usize> LatticeSearch<N> {
+    /// Chooses the offsets of the first "axes" axes, given the sum and the sum of
+    /// squares of the offsets already chosen for the others
+    fn visit(&mut self, axes: usize, offset_sum: f32, offset_squares: f32) {
+        if axes == 0 {
+            let lattice_sum = self.lattice.iter().map(|&l| l as f32).sum::<f32>() * self.unskew;
+            let mut distance = self.point;
+            for (d, &l) in distance.iter_mut().zip(self.lattice.iter()) {
+                *d -= l as f32 - lattice_sum;
+            }
+            self.sum += kernel(self.radius_squared, &distance) * (self.gradient)(hash(self.seed, &self.lattice), &distance);
+            return;
+        }
+
+        // Lowest distance squared over every real offset of the remaining free
+        // axes, reached when they share the offset sum evenly
+        let axis = axes - 1;
+        let free_scale = self.squash / (1.0 - self.squash * axis as f32);
+        for offset in -1..=2 {
+            let u = offset as f32 - self.fraction[axis];
+            let sum = offset_sum + u;
+            let squares = offset_squares + u * u;
+            if squares - sum * sum * free_scale < self.radius_squared {
+                self.lattice[axis] = self.cell[axis] as i32 + offset;
+                self.visit(axis, sum, squares);
+            }
+        }
+    }
+}
+
+/// Falloff of a lattice point's contribution, (r² - d²)⁴ inside the radius
+fn kernel<const N: usize>(radius_squared: f32, distance: &[f32; N]) -> f32 {
+    let t = radius_squared - distance.iter().map(|d| d * d).sum::<f32>();
+    if t > 0.0 {
+        let t2 = t * t;
+        t2 * t2
+    } else {
+        0.0
+    }
+}
+
+/// Dot product with one of 24 unit gradients, every 15 degrees
+fn gradient_2d(hash: u32, distance: &[f32; 2]) -> f32 {
+    let index = hash % 24;
+    let [x, y] = GRADIENTS_2D[index as usize % 6];
+    let (gx, gy) = match index / 6 {
+        0 => (x, y),
+        1 => (-y, x),
+        2 => (-x, -y),
+        _ => (y, -x),
+    };
+    gx * distance[0] + gy * distance[1]
+}
+
+/// Dot product with one of 48 unit gradients: the permutations and sign changes
+/// of (a, a, b) and (c, d, 0), spread evenly over the sphere
+fn gradient_3d(hash: u32, distance: &[f32; 3]) -> f32 {
+    const A: f32 = 0.673_887_3;
+    const B: f32 = 0.302_905_45;
+    const C: f32 = 0.934_846_9;
+    const D: f32 = 0.355_051_03;
+
+    let index = hash % 48;
+    let family = (index >> 3) as usize;
+    let mut gradient = [0.0; 3];
+    if family < 3 {
+        gradient = [A; 3];
+        gradient[family] = B;
+    } else {
+        // The sign bit of the zero component picks the order of c and d instead
+        let zero = family - 3;
+        let (first, second) = if index & (1 << zero) != 0 { (C, D) } else { (D, C) };
+        gradient[(zero + 1) % 3] = first;
+        gradient[(zero + 2) % 3] = second;
+    }
+
+    gradient.iter().zip(distance.iter()).enumerate().fold(0.0, |sum, (axis, (&g, &d))| {
+        if index & (1 << axis) != 0 {
+            sum - g * d
+        } else {
+            sum + g * d
+        }
+    })
+}
+
+/// Dot product with one of 32 gradients, the permutations and sign changes of (1, 1, 1, 0)
+fn gradient_4d(hash: u32, distance: &[f32; 4]) -> f32 {
+    let index = hash % 32;
+    let zero = (index >> 3) as usize;
+    let mut sign_bit = 0;
+    let mut sum = 0.0;
+    for (axis, &d) in distance.iter().enumerate() {
+        if axis != zero {
+            sum += if index & (1 << sign_bit) != 0 { -d } else { d };
+            sign_bit += 1;
+        }
+    }
+    sum
+}
+
+/// Smooth step with zero first and second derivatives at 0 and 1
+fn quintic(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Squared length of the gradients used in "dimensions", see `gradient_dot`
+fn gradient_length_squared(dimensions: usize) -> f32 {
+    if dimensions > 2 {
+        (dimensions - 1) as f32
+    } else {
+        dimensions as f32
+    }
+}
+
+/// Returns the dot product of the gradient picked by "hash" and "distance".
+/// Gradients have components of 1 or -1, with one left at zero above two dimensions,
+/// like the edge midpoints of a cube
+fn gradient_dot<const N: usize>(hash: u32, distance: &[f32; N]) -> f32 {
+    let zero = if N > 2 { (hash >> 16) as usize % N } else { N };
+    distance.iter().enumerate().filter(|&(axis, _)| axis != zero).fold(0.0, |sum, (axis, &d)| {
+        if hash & (1 << axis) != 0 {
+            sum - d
+        } else {
+            sum + d
+        }
+    })
+}
+
+/// Hashes a lattice point
+fn hash<const N: usize>(seed: u32, lattice: &[i32; N]) -> u32 {
+    lattice.iter().fold(mix(seed ^ 0x9e37_79b9), |h, &c| mix(h ^ c as u32))
+}
+
+/// Scrambles the bits of a value, the finalizer of MurmurHash3
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// Turns the top 24 bits of a hash into a float in [0, 1)
+fn unit_float(hash: u32) -> f32 {
+    (hash >> 8) as f32 / (1 << 24) as f32
+}
+
+/// Steps to the next offset in {min, ..., max}^N, returning false after the last one
+fn next_offset<const N: usize>(offset: &mut [i32; N], min: i32, max: i32) -> bool {
+    for o in offset.iter_mut() {
+        if *o < max {
+            *o += 1;
+            return true;
+        }
+        *o = min;
+    }
+    false
+}
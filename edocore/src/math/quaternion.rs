@@ -0,0 +1,316 @@
+//=============================================================================
+// quaternion.rs
+// Rotations represented as unit quaternions
+//=============================================================================
+
+use core::ops::{Mul, Neg};
+use core::fmt::{Display, Formatter, Error};
+use crate::math::angle::{Degrees, Radians};
+use crate::math::matrix::Matrix;
+use crate::math::scalar::{Real, Scalar};
+use crate::math::vector::Vector;
+
+/// A rotation in three dimensional space
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Quaternion<T = f32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+/// A rotation in double precision
+pub type DQuaternion = Quaternion<f64>;
+
+impl<T: Real> Quaternion<T> {
+    /// Returns the angle between two rotations
+    pub fn angle(a: Self, b: Self) -> Degrees<T> {
+        let dot = Quaternion::dot(a, b).abs();
+        let dot = if dot > T::ONE { T::ONE } else { dot };
+        Radians(two::<T>() * dot.acos()).into()
+    }
+
+    /// Creates a rotation of "angle" around "axis"
+    pub fn axis_angle(axis: Vector<T, 3>, angle: impl Into<Radians<T>>) -> Self {
+        let (sin, cos) = (angle.into() / two::<T>()).sin_cos();
+        let v = axis.normalized() * sin;
+        Quaternion { x: v.x, y: v.y, z: v.z, w: cos }
+    }
+
+    /// Returns the conjugate of this rotation
+    pub fn conjugate(self) -> Self {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// Computes the dot product of two quaternions
+    pub fn dot(a: Self, b: Self) -> T {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    /// Creates a rotation from euler angles.
+    /// The rotation is applied around z first, then x, then y
+    pub fn euler(x: impl Into<Radians<T>>, y: impl Into<Radians<T>>, z: impl Into<Radians<T>>) -> Self {
+        let qx = Quaternion::axis_angle(Vector::<T, 3>::new(T::ONE, T::ZERO, T::ZERO), x);
+        let qy = Quaternion::axis_angle(Vector::<T, 3>::new(T::ZERO, T::ONE, T::ZERO), y);
+        let qz = Quaternion::axis_angle(Vector::<T, 3>::new(T::ZERO, T::ZERO, T::ONE), z);
+        qy * qx * qz
+    }
+
+    /// Returns the euler angles of this rotation, see `euler`
+    pub fn euler_angles(self) -> Vector<Degrees<T>, 3> {
+        let m = self.to_matrix().values;
+        let sin_x = -m[1][2];
+
+        let (x, y, z) = if sin_x.abs() < T::ONE - T::EPSILON {
+            (sin_x.asin(), m[0][2].atan2(m[2][2]), m[1][0].atan2(m[1][1]))
+        } else {
+            // Gimbal lock, all of the remaining rotation is put on y
+            (T::FRAC_PI_2.copysign(sin_x), (-m[2][0]).atan2(m[0][0]), T::ZERO)
+        };
+
+        Vector::<Degrees<T>, 3>::new(Radians(x).into(), Radians(y).into(), Radians(z).into())
+    }
+
+    /// Creates a rotation from the upper 3x3 part of a matrix.
+    /// The matrix should not contain any scale
+    pub fn from_matrix(matrix: Matrix<T, 4>) -> Self {
+        let m = matrix.values;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let quarter = T::from_f32(0.25);
+
+        let q = if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * two::<T>();
+            Quaternion {
+                x: (m[2][1] - m[1][2]) / s,
+                y: (m[0][2] - m[2][0]) / s,
+                z: (m[1][0] - m[0][1]) / s,
+                w: quarter * s,
+            }
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (T::ONE + m[0][0] - m[1][1] - m[2][2]).sqrt() * two::<T>();
+            Quaternion {
+                x: quarter * s,
+                y: (m[0][1] + m[1][0]) / s,
+                z: (m[0][2] + m[2][0]) / s,
+                w: (m[2][1] - m[1][2]) / s,
+            }
+        } else if m[1][1] > m[2][2] {
+            let s = (T::ONE + m[1][1] - m[0][0] - m[2][2]).sqrt() * two::<T>();
+            Quaternion {
+                x: (m[0][1] + m[1][0]) / s,
+                y: quarter * s,
+                z: (m[1][2] + m[2][1]) / s,
+                w: (m[0][2] - m[2][0]) / s,
+            }
+        } else {
+            let s = (T::ONE + m[2][2] - m[0][0] - m[1][1]).sqrt() * two::<T>();
+            Quaternion {
+                x: (m[0][2] + m[2][0]) / s,
+                y: (m[1][2] + m[2][1]) / s,
+                z: quarter * s,
+                w: (m[1][0] - m[0][1]) / s,
+            }
+        };
+
+        q.normalized()
+    }
+
+    /// Returns the rotation that does nothing
+    pub fn identity() -> Self {
+        Quaternion { x: T::ZERO, y: T::ZERO, z: T::ZERO, w: T::ONE }
+    }
+
+    /// Returns the rotation that undoes this rotation
+    pub fn inverse(self) -> Self {
+        let sqr_mag = Quaternion::dot(self, self);
+        if sqr_mag > T::EPSILON {
+            let c = self.conjugate();
+            Quaternion { x: c.x / sqr_mag, y: c.y / sqr_mag, z: c.z / sqr_mag, w: c.w / sqr_mag }
+        } else {
+            Quaternion::identity()
+        }
+    }
+
+    /// Creates a rotation that looks along "forward" with the top facing "up".
+    /// The z axis is mapped onto "forward"
+    pub fn look_rotation(forward: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let forward = forward.normalized();
+        let mut right = Vector::cross(up, forward).normalized();
+        if right.square_magnitude() == T::ZERO {
+            // "up" is parallel to "forward", pick any perpendicular axis
+            let fallback = if forward.x.abs() < T::from_f32(0.9) {
+                Vector::<T, 3>::new(T::ONE, T::ZERO, T::ZERO)
+            } else {
+                Vector::<T, 3>::new(T::ZERO, T::ZERO, T::ONE)
+            };
+            right = Vector::cross(Vector::cross(forward, fallback), forward).normalized();
+        }
+        let up = Vector::cross(forward, right);
+
+        Quaternion::from_matrix(Matrix {
+            values: [[right.x, up.x, forward.x, T::ZERO],
+                [right.y, up.y, forward.y, T::ZERO],
+                [right.z, up.z, forward.z, T::ZERO],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        })
+    }
+
+    /// Returns the length of the quaternion
+    pub fn magnitude(self) -> T {
+        Quaternion::dot(self, self).sqrt()
+    }
+
+    /// Creates a new quaternion from its raw components
+    #[inline]
+    pub fn new(x_val: T, y_val: T, z_val: T, w_val: T) -> Self {
+        Quaternion { x: x_val, y: y_val, z: z_val, w: w_val }
+    }
+
+    /// Linearly interpolates between two rotations and normalizes the result.
+    /// Cheaper than `slerp` but does not rotate at a constant speed
+    pub fn nlerp(a: Self, b: Self, t: T) -> Self {
+        // Take the shortest path
+        let b = if Quaternion::dot(a, b) < T::ZERO { -b } else { b };
+        Quaternion {
+            x: a.x + (b.x - a.x) * t,
+            y: a.y + (b.y - a.y) * t,
+            z: a.z + (b.z - a.z) * t,
+            w: a.w + (b.w - a.w) * t,
+        }.normalized()
+    }
+
+    /// Returns this quaternion with a magnitude of 1
+    pub fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        if mag > T::EPSILON {
+            Quaternion { x: self.x / mag, y: self.y / mag, z: self.z / mag, w: self.w / mag }
+        } else {
+            Quaternion::identity()
+        }
+    }
+
+    /// Spherically interpolates between two rotations
+    pub fn slerp(a: Self, b: Self, t: T) -> Self {
+        let mut dot = Quaternion::dot(a, b);
+        let b = if dot < T::ZERO {
+            dot = -dot;
+            -b
+        } else {
+            b
+        };
+
+        // Nearly identical rotations, avoid dividing by sin(0)
+        if dot > T::ONE - T::EPSILON {
+            return Quaternion::nlerp(a, b, t);
+        }
+
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let wa = ((T::ONE - t) * theta).sin() / sin_theta;
+        let wb = (t * theta).sin() / sin_theta;
+
+        Quaternion {
+            x: a.x * wa + b.x * wb,
+            y: a.y * wa + b.y * wb,
+            z: a.z * wa + b.z * wb,
+            w: a.w * wa + b.w * wb,
+        }
+    }
+
+    /// Returns the rotation axis and the angle around it
+    pub fn to_axis_angle(self) -> (Vector<T, 3>, Degrees<T>) {
+        let q = self.normalized();
+        let sin_sqr = T::ONE - q.w * q.w;
+        let sin_half = if sin_sqr > T::ZERO { sin_sqr.sqrt() } else { T::ZERO };
+        if sin_half < T::EPSILON {
+            return (Vector::<T, 3>::new(T::ONE, T::ZERO, T::ZERO), Degrees(T::ZERO));
+        }
+
+        let axis = Vector::<T, 3>::new(q.x / sin_half, q.y / sin_half, q.z / sin_half);
+        let cos_half = if q.w > T::ONE { T::ONE } else { q.w };
+        (axis, Radians(two::<T>() * cos_half.acos()).into())
+    }
+
+    /// Converts the rotation into a 4x4 rotation matrix
+    pub fn to_matrix(self) -> Matrix<T, 4> {
+        let Quaternion { x, y, z, w } = self;
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+        let (one, two) = (T::ONE, two::<T>());
+
+        Matrix {
+            values: [[one - two * (yy + zz), two * (xy - wz), two * (xz + wy), T::ZERO],
+                [two * (xy + wz), one - two * (xx + zz), two * (yz - wx), T::ZERO],
+                [two * (xz - wy), two * (yz + wx), one - two * (xx + yy), T::ZERO],
+                [T::ZERO, T::ZERO, T::ZERO, T::ONE]]
+        }
+    }
+}
+
+impl DQuaternion {
+    /// Converts into a single precision rotation, for rendering
+    pub fn to_f32(self) -> Quaternion {
+        Quaternion { x: self.x as f32, y: self.y as f32, z: self.z as f32, w: self.w as f32 }
+    }
+}
+
+/// Returns 2 in any scalar type
+fn two<T: Scalar>() -> T {
+    T::ONE + T::ONE
+}
+
+impl<T: Display> Display for Quaternion<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+/// Widens a rotation to double precision, exactly
+impl From<Quaternion> for DQuaternion {
+    fn from(q: Quaternion) -> Self {
+        Quaternion { x: q.x as f64, y: q.y as f64, z: q.z as f64, w: q.w as f64 }
+    }
+}
+
+impl<T: Real> From<Quaternion<T>> for Matrix<T, 4> {
+    fn from(q: Quaternion<T>) -> Self {
+        q.to_matrix()
+    }
+}
+
+// Operator implementations
+/// Combines two rotations, "rhs" is applied first
+impl<T: Real> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Quaternion<T>) -> Self::Output {
+        Quaternion {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+/// Rotates a vector
+impl<T: Real> Mul<Vector<T, 3>> for Quaternion<T> {
+    type Output = Vector<T, 3>;
+
+    fn mul(self, rhs: Vector<T, 3>) -> Self::Output {
+        // v' = v + 2w(q x v) + 2(q x (q x v))
+        let q = Vector::<T, 3>::new(self.x, self.y, self.z);
+        let t = Vector::cross(q, rhs) * two::<T>();
+        rhs + t * self.w + Vector::cross(q, t)
+    }
+}
+
+impl<T: Real> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
@@ -0,0 +1,275 @@
+//=============================================================================
+// random.rs
+// Seedable pseudo random numbers and random points in shapes
+//
+// Shapes are sampled by rejection instead of with sin and cos, whose results
+// can differ between platforms, so a seed gives the same values everywhere.
+//=============================================================================
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::math::float::FloatMath;
+use crate::math::geometry::Aabb;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::{Vector2, Vector3};
+
+const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+/// Candidates tried around a point before `poisson_disk` gives up on it
+const POISSON_ATTEMPTS: usize = 30;
+
+/// A PCG32 generator. Generators with the same seed but different streams
+/// give unrelated sequences, so every chunk or thread can have its own
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Random {
+    state: u64,
+    increment: u64,
+}
+
+impl Random {
+    /// Returns a point on the edge of a disk with radius "radius" and a
+    /// random center inside it, see `poisson_disk`
+    fn annulus_point(&mut self, center: Vector2, radius: f32) -> Vector2 {
+        center + self.on_unit_circle() * self.range_f32(radius, 2.0 * radius)
+    }
+
+    /// Returns a random direction within 90 degrees of "normal", more likely the
+    /// closer it is to "normal". This is the distribution of diffuse light
+    pub fn cosine_hemisphere(&mut self, normal: Vector3) -> Vector3 {
+        let disk = self.inside_unit_circle();
+        let up = FloatMath::sqrt((1.0 - Vector2::dot(disk, disk)).max(0.0));
+        let (tangent, bitangent) = basis(normal);
+        tangent * disk.x + bitangent * disk.y + normal * up
+    }
+
+    /// Returns a random point inside "aabb"
+    pub fn inside_aabb(&mut self, aabb: Aabb) -> Vector3 {
+        Vector3::new(
+            self.range_f32(aabb.min.x, aabb.max.x),
+            self.range_f32(aabb.min.y, aabb.max.y),
+            self.range_f32(aabb.min.z, aabb.max.z),
+        )
+    }
+
+    /// Returns a random point inside the circle with a radius of 1
+    pub fn inside_unit_circle(&mut self) -> Vector2 {
+        loop {
+            let point = Vector2::new(self.range_f32(-1.0, 1.0), self.range_f32(-1.0, 1.0));
+            if Vector2::dot(point, point) <= 1.0 {
+                return point;
+            }
+        }
+    }
+
+    /// Returns a random point inside the sphere with a radius of 1
+    pub fn inside_unit_sphere(&mut self) -> Vector3 {
+        loop {
+            let point = Vector3::new(self.range_f32(-1.0, 1.0), self.range_f32(-1.0, 1.0), self.range_f32(-1.0, 1.0));
+            if Vector3::dot(point, point) <= 1.0 {
+                return point;
+            }
+        }
+    }
+
+    /// Creates a generator on the default stream
+    pub fn new(seed: u64) -> Random {
+        Random::with_stream(seed, 0)
+    }
+
+    /// Returns a random boolean
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    /// Returns a random float in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns a random float in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random integer
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(MULTIPLIER).wrapping_add(self.increment);
+
+        let shifted = (((old >> 18) ^ old) >> 27) as u32;
+        shifted.rotate_right((old >> 59) as u32)
+    }
+
+    /// Returns a random integer
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        (high << 32) | self.next_u32() as u64
+    }
+
+    /// Returns a random direction within 90 degrees of "normal"
+    pub fn on_hemisphere(&mut self, normal: Vector3) -> Vector3 {
+        let direction = self.on_unit_sphere();
+        if Vector3::dot(direction, normal) < 0.0 {
+            -direction
+        } else {
+            direction
+        }
+    }
+
+    /// Returns a random point on the circle with a radius of 1
+    pub fn on_unit_circle(&mut self) -> Vector2 {
+        loop {
+            let point = self.inside_unit_circle();
+            let square_magnitude = Vector2::dot(point, point);
+            if square_magnitude > 0.0001 {
+                return point / FloatMath::sqrt(square_magnitude);
+            }
+        }
+    }
+
+    /// Returns a random point on the sphere with a radius of 1
+    pub fn on_unit_sphere(&mut self) -> Vector3 {
+        loop {
+            let point = self.inside_unit_sphere();
+            let square_magnitude = Vector3::dot(point, point);
+            if square_magnitude > 0.0001 {
+                return point / FloatMath::sqrt(square_magnitude);
+            }
+        }
+    }
+
+    /// Returns points in the rectangle between "min" and "max" that are at least "radius"
+    /// apart, spread evenly without visible patterns (Bridson's algorithm)
+    pub fn poisson_disk(&mut self, min: Vector2, max: Vector2, radius: f32) -> Vec<Vector2> {
+        let size = max - min;
+        if radius <= 0.0 || size.x < 0.0 || size.y < 0.0 {
+            return Vec::new();
+        }
+
+        // Cells small enough to hold at most one point each
+        let cell = radius / core::f32::consts::SQRT_2;
+        let columns = (FloatMath::ceil(size.x / cell) as usize).max(1);
+        let rows = (FloatMath::ceil(size.y / cell) as usize).max(1);
+        let cell_of = |point: Vector2| {
+            let column = (((point.x - min.x) / cell) as usize).min(columns - 1);
+            let row = (((point.y - min.y) / cell) as usize).min(rows - 1);
+            (column, row)
+        };
+
+        let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+        let mut points = Vec::new();
+        let mut active = Vec::new();
+
+        let first = min + Vector2::new(self.next_f32() * size.x, self.next_f32() * size.y);
+        let (column, row) = cell_of(first);
+        grid[row * columns + column] = Some(0);
+        points.push(first);
+        active.push(0);
+
+        while !active.is_empty() {
+            let index = self.range_u32(active.len() as u32) as usize;
+            let center = points[active[index]];
+            let mut found = false;
+
+            for _ in 0..POISSON_ATTEMPTS {
+                let candidate = self.annulus_point(center, radius);
+                if candidate.x < min.x || candidate.y < min.y || candidate.x > max.x || candidate.y > max.y {
+                    continue;
+                }
+
+                // Only the 5x5 cells around the candidate can hold a point closer than "radius"
+                let (column, row) = cell_of(candidate);
+                let too_close = (row.saturating_sub(2)..(row + 3).min(rows)).any(|r| {
+                    (column.saturating_sub(2)..(column + 3).min(columns)).any(|c| match grid[r * columns + c] {
+                        Some(other) => (points[other] - candidate).square_magnitude() < radius * radius,
+                        None => false,
+                    })
+                });
+
+                if !too_close {
+                    grid[row * columns + column] = Some(points.len());
+                    active.push(points.len());
+                    points.push(candidate);
+                    found = true;
+                    break;
+                }
+            }
+
+            if !found {
+                active.swap_remove(index);
+            }
+        }
+
+        points
+    }
+
+    /// Returns a random float in [min, max)
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        // Rounding can land on max when the range is wide compared to the float spacing
+        let value = min + (max - min) * self.next_f32();
+        if value < max {
+            value
+        } else {
+            min
+        }
+    }
+
+    /// Returns a random integer in [min, max)
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min < max, "the range is empty");
+        min.wrapping_add(self.range_u32(max.wrapping_sub(min) as u32) as i32)
+    }
+
+    /// Returns a random integer in [0, bound) without favouring any of them
+    pub fn range_u32(&mut self, bound: u32) -> u32 {
+        assert!(bound > 0, "the range is empty");
+
+        // Lemire's method, the low bits decide when a value has to be rerolled
+        let mut product = self.next_u32() as u64 * bound as u64;
+        if (product as u32) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u32) < threshold {
+                product = self.next_u32() as u64 * bound as u64;
+            }
+        }
+
+        (product >> 32) as u32
+    }
+
+    /// Returns a random rotation, every orientation being equally likely
+    pub fn rotation(&mut self) -> Quaternion {
+        // Marsaglia's method, two points in the unit disk make a point on the 4D sphere
+        let (a, b) = loop {
+            let a = self.inside_unit_circle();
+            let b = self.inside_unit_circle();
+            if Vector2::dot(b, b) > 0.0001 {
+                break (a, b);
+            }
+        };
+        let scale = FloatMath::sqrt((1.0 - Vector2::dot(a, a)) / Vector2::dot(b, b));
+        Quaternion::new(a.x, a.y, b.x * scale, b.y * scale)
+    }
+
+    /// Creates a new generator on a stream derived from this one, so work can be
+    /// handed off without the two sequences overlapping
+    pub fn split(&mut self) -> Random {
+        let seed = self.next_u64();
+        Random::with_stream(seed, self.next_u64())
+    }
+
+    /// Creates a generator on one of 2^63 streams
+    pub fn with_stream(seed: u64, stream: u64) -> Random {
+        let mut random = Random { state: 0, increment: (stream << 1) | 1 };
+        random.next_u32();
+        random.state = random.state.wrapping_add(seed);
+        random.next_u32();
+        random
+    }
+}
+
+/// Returns two directions perpendicular to "normal" and each other
+fn basis(normal: Vector3) -> (Vector3, Vector3) {
+    let other = if normal.x.abs() < 0.9 { Vector3::new(1.0, 0.0, 0.0) } else { Vector3::new(0.0, 1.0, 0.0) };
+    let tangent = Vector3::cross(normal, other).normalized();
+    (tangent, Vector3::cross(normal, tangent))
+}
@@ -0,0 +1,190 @@
+//=============================================================================
+// scalar.rs
+// Numeric traits shared by the generic math types
+//=============================================================================
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use crate::math::float::FloatMath;
+use crate::math::vector::Vector;
+
+/// A number that can be stored in a vector or matrix
+pub trait Scalar:
+    'static
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+
+    /// Computes the dot product of two vectors, `Vector::dot` calls this so that a
+    /// type can sum the products in a wider type
+    fn dot<const N: usize>(lhs: Vector<Self, N>, rhs: Vector<Self, N>) -> Self {
+        let mut sum = Self::ZERO;
+        for i in 0..N {
+            sum = sum + lhs.values[i] * rhs.values[i];
+        }
+        sum
+    }
+}
+
+/// A floating point scalar
+pub trait Float: Scalar + Neg<Output = Self> {
+    /// Tolerance used when a value is considered to be zero
+    const EPSILON: Self;
+    /// The difference between 1.0 and the next representable value
+    const MACHINE_EPSILON: Self;
+
+    /// Converts an f32 into this type
+    fn from_f32(value: f32) -> Self;
+    /// Converts this value into an f32, losing precision if it has more
+    fn to_f32(self) -> f32;
+
+    fn abs(self) -> Self;
+    fn acos(self) -> Self;
+    fn ceil(self) -> Self;
+    fn floor(self) -> Self;
+    /// Returns the magnitude of a vector, `Vector::magnitude` calls this so that a
+    /// type can take the root of a square magnitude it can't represent
+    fn magnitude<const N: usize>(vector: Vector<Self, N>) -> Self {
+        vector.square_magnitude().sqrt()
+    }
+    /// Rounds half-way cases away from zero
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn to_degrees(self) -> Self;
+}
+
+/// A floating point scalar with trigonometry, implemented by f32 and f64
+pub trait Real: Float {
+    /// Half of pi
+    const FRAC_PI_2: Self;
+    /// Archimedes' constant, half a turn in radians
+    const PI: Self;
+
+    fn asin(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn cos(self) -> Self;
+    fn sin(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn tan(self) -> Self;
+    fn to_radians(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0 as $t;
+            const ONE: Self = 1 as $t;
+        }
+    )*};
+}
+
+impl_scalar!(f32, f64, i32, u32);
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {$(
+        impl Float for $t {
+            const EPSILON: Self = crate::math::EPSILON as $t;
+            const MACHINE_EPSILON: Self = $t::EPSILON;
+
+            #[inline]
+            fn from_f32(value: f32) -> Self {
+                value as $t
+            }
+
+            #[inline]
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                FloatMath::acos(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                FloatMath::ceil(self)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                FloatMath::floor(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                FloatMath::round(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                FloatMath::sqrt(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+        }
+
+        impl Real for $t {
+            const FRAC_PI_2: Self = core::$t::consts::FRAC_PI_2;
+            const PI: Self = core::$t::consts::PI;
+
+            #[inline]
+            fn asin(self) -> Self {
+                FloatMath::asin(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                FloatMath::atan2(self, other)
+            }
+
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                $t::copysign(self, sign)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                FloatMath::cos(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                FloatMath::sin(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                FloatMath::sin_cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                FloatMath::tan(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
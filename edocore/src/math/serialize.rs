@@ -0,0 +1,107 @@
+//=============================================================================
+// serialize.rs
+// Serde support for the math types
+//
+// Vectors, quaternions and matrices are written as arrays, for example
+// [x, y, z] and [[a, b], [c, d]], so text formats stay readable.
+//=============================================================================
+
+use alloc::vec::Vec;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::math::matrix::Matrix;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::Vector;
+
+/// Serializes a fixed size array of any length as a tuple
+struct Array<'a, T, const N: usize>(&'a [T; N]);
+
+impl<'a, T: Serialize, const N: usize> Serialize for Array<'a, T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for value in self.0.iter() {
+            tuple.serialize_element(value)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Reads exactly N values of T
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(formatter, "an array of length {}", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values: [Option<T>; N] = [(); N].map(|_| None);
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = Some(seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?);
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self));
+        }
+
+        Ok(values.map(|value| value.unwrap()))
+    }
+}
+
+/// Deserializes a list that needs at least MIN values, such as the points of a spline
+pub(crate) fn deserialize_at_least<'de, D: Deserializer<'de>, T: Deserialize<'de>, const MIN: usize>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    let values = Vec::<T>::deserialize(deserializer)?;
+    if values.len() < MIN {
+        return Err(D::Error::custom(format_args!("expected at least {} values, found {}", MIN, values.len())));
+    }
+    Ok(values)
+}
+
+impl<T: Serialize, const N: usize> Serialize for Vector<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Array(&self.values).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = deserializer.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))?;
+        Ok(Vector { values })
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for Matrix<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for row in self.values.iter() {
+            tuple.serialize_element(&Array(row))?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Matrix<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = deserializer.deserialize_tuple(N, ArrayVisitor::<Vector<T, N>, N>(PhantomData))?;
+        Ok(Matrix { values: rows.map(|row| row.values) })
+    }
+}
+
+impl<T: Serialize> Serialize for Quaternion<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.x, &self.y, &self.z, &self.w).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Quaternion<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, z, w) = <(T, T, T, T)>::deserialize(deserializer)?;
+        Ok(Quaternion { x, y, z, w })
+    }
+}
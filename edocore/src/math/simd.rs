@@ -0,0 +1,163 @@
+//=============================================================================
+// simd.rs
+// SSE2/AVX versions of the Vector4 and Matrix4 hot paths
+//
+// Every function returns None unless it is called with f32 values of
+// dimension 4, the callers then fall back to the scalar implementation.
+// Results are computed in the same order as the scalar code so both paths
+// produce identical bits.
+//=============================================================================
+
+use core::arch::x86_64::*;
+use core::any::TypeId;
+
+/// Component-wise vector operations
+#[derive(Copy, Clone)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Reinterprets a value as another type if both are the same type
+#[inline(always)]
+fn reinterpret<A: 'static + Copy, B: 'static + Copy>(value: A) -> Option<B> {
+    if TypeId::of::<A>() == TypeId::of::<B>() {
+        // The types are identical, this is a plain copy
+        Some(unsafe { core::mem::transmute_copy(&value) })
+    } else {
+        None
+    }
+}
+
+#[inline(always)]
+unsafe fn apply(op: Op, a: __m128, b: __m128) -> __m128 {
+    match op {
+        Op::Add => _mm_add_ps(a, b),
+        Op::Sub => _mm_sub_ps(a, b),
+        Op::Mul => _mm_mul_ps(a, b),
+        Op::Div => _mm_div_ps(a, b),
+    }
+}
+
+/// Applies "op" to each component of two vectors
+#[inline]
+pub(crate) fn vector4<T: 'static + Copy, const N: usize>(a: [T; N], b: [T; N], op: Op) -> Option<[T; N]> {
+    let a: [f32; 4] = reinterpret(a)?;
+    let b: [f32; 4] = reinterpret(b)?;
+
+    let mut res = [0.0f32; 4];
+    unsafe {
+        let v = apply(op, _mm_loadu_ps(a.as_ptr()), _mm_loadu_ps(b.as_ptr()));
+        _mm_storeu_ps(res.as_mut_ptr(), v);
+    }
+    reinterpret(res)
+}
+
+/// Applies "op" between each component of a vector and a scalar
+#[inline]
+pub(crate) fn vector4_scalar<T: 'static + Copy, const N: usize>(a: [T; N], b: T, op: Op) -> Option<[T; N]> {
+    let a: [f32; 4] = reinterpret(a)?;
+    let b: f32 = reinterpret(b)?;
+
+    let mut res = [0.0f32; 4];
+    unsafe {
+        let v = apply(op, _mm_loadu_ps(a.as_ptr()), _mm_set1_ps(b));
+        _mm_storeu_ps(res.as_mut_ptr(), v);
+    }
+    reinterpret(res)
+}
+
+/// Computes the dot product of two vectors
+#[inline]
+pub(crate) fn dot4<T: 'static + Copy, const N: usize>(a: [T; N], b: [T; N]) -> Option<T> {
+    let a: [f32; 4] = reinterpret(a)?;
+    let b: [f32; 4] = reinterpret(b)?;
+
+    let res = unsafe {
+        let p = _mm_mul_ps(_mm_loadu_ps(a.as_ptr()), _mm_loadu_ps(b.as_ptr()));
+        // Sum the lanes one at a time, starting from zero like the scalar code
+        let mut sum = _mm_add_ss(_mm_setzero_ps(), p);
+        sum = _mm_add_ss(sum, _mm_shuffle_ps(p, p, 0b01_01_01_01));
+        sum = _mm_add_ss(sum, _mm_movehl_ps(p, p));
+        sum = _mm_add_ss(sum, _mm_shuffle_ps(p, p, 0b11_11_11_11));
+        _mm_cvtss_f32(sum)
+    };
+    reinterpret(res)
+}
+
+/// Multiplies two 4x4 matrices
+#[inline]
+pub(crate) fn matrix4_mul<T: 'static + Copy, const N: usize>(a: [[T; N]; N], b: [[T; N]; N]) -> Option<[[T; N]; N]> {
+    let a: [[f32; 4]; 4] = reinterpret(a)?;
+    let b: [[f32; 4]; 4] = reinterpret(b)?;
+
+    let mut res = [[0.0f32; 4]; 4];
+    unsafe { mul_rows(&a, &b, &mut res) };
+    reinterpret(res)
+}
+
+/// Multiplies a 4x4 matrix with a column vector
+#[inline]
+pub(crate) fn matrix4_transform<T: 'static + Copy, const N: usize>(m: [[T; N]; N], v: [T; N]) -> Option<[T; N]> {
+    let m: [[f32; 4]; 4] = reinterpret(m)?;
+    let v: [f32; 4] = reinterpret(v)?;
+
+    let mut res = [0.0f32; 4];
+    unsafe {
+        // Each lane accumulates one row: ((0 + m0 * v0) + m1 * v1) + ...
+        let mut acc = _mm_setzero_ps();
+        for (k, value) in v.iter().enumerate() {
+            let column = _mm_set_ps(m[3][k], m[2][k], m[1][k], m[0][k]);
+            acc = _mm_add_ps(acc, _mm_mul_ps(column, _mm_set1_ps(*value)));
+        }
+        _mm_storeu_ps(res.as_mut_ptr(), acc);
+    }
+    reinterpret(res)
+}
+
+/// Each row of the result is the rows of "b" weighted by a row of "a"
+#[cfg(not(target_feature = "avx"))]
+#[inline(always)]
+unsafe fn mul_rows(a: &[[f32; 4]; 4], b: &[[f32; 4]; 4], res: &mut [[f32; 4]; 4]) {
+    let b_rows = [
+        _mm_loadu_ps(b[0].as_ptr()),
+        _mm_loadu_ps(b[1].as_ptr()),
+        _mm_loadu_ps(b[2].as_ptr()),
+        _mm_loadu_ps(b[3].as_ptr()),
+    ];
+
+    for (row, out) in a.iter().zip(res.iter_mut()) {
+        let mut acc = _mm_setzero_ps();
+        for (weight, b_row) in row.iter().zip(b_rows.iter()) {
+            acc = _mm_add_ps(acc, _mm_mul_ps(_mm_set1_ps(*weight), *b_row));
+        }
+        _mm_storeu_ps(out.as_mut_ptr(), acc);
+    }
+}
+
+/// AVX version of `mul_rows` working on two rows at a time
+#[cfg(target_feature = "avx")]
+#[inline(always)]
+unsafe fn mul_rows(a: &[[f32; 4]; 4], b: &[[f32; 4]; 4], res: &mut [[f32; 4]; 4]) {
+    // Every row of "b" duplicated into both halves
+    let mut b_rows = [_mm256_setzero_ps(); 4];
+    for (b_row, values) in b_rows.iter_mut().zip(b.iter()) {
+        let half = _mm_loadu_ps(values.as_ptr());
+        *b_row = _mm256_set_m128(half, half);
+    }
+
+    let out = res.as_mut_ptr() as *mut f32;
+    for pair in 0..2 {
+        let top = &a[pair * 2];
+        let bottom = &a[pair * 2 + 1];
+
+        let mut acc = _mm256_setzero_ps();
+        for (k, b_row) in b_rows.iter().enumerate() {
+            let weights = _mm256_set_m128(_mm_set1_ps(bottom[k]), _mm_set1_ps(top[k]));
+            acc = _mm256_add_ps(acc, _mm256_mul_ps(weights, *b_row));
+        }
+        _mm256_storeu_ps(out.add(pair * 8), acc);
+    }
+}
@@ -0,0 +1,73 @@
+//=============================================================================
+// swizzle.rs
+// Shader style component reordering, such as `v.xz()`, `v.zyx()` or `c.rgb()`
+//
+// Every combination of two, three and four components is generated, repeats
+// included, with both the xyzw and the rgba names.
+//=============================================================================
+
+use paste::paste;
+use crate::math::vector::Vector;
+
+macro_rules! swizzles {
+    // Generates the methods of a vector with the given components
+    ($n:literal: $($c:ident $i:tt)*) => {
+        impl<T: Copy> Vector<T, $n> {
+            swizzles!(@two [$($c $i)*] [$($c $i)*]);
+            swizzles!(@three [$($c $i)*] [$($c $i)*]);
+            swizzles!(@four [$($c $i)*] [$($c $i)*]);
+        }
+    };
+
+    (@two [$($a:ident $ia:tt)*] $all:tt) => {
+        $(swizzles!(@two_last $a $ia $all);)*
+    };
+    (@two_last $a:ident $ia:tt [$($b:ident $ib:tt)*]) => {
+        paste! {$(
+            #[inline]
+            pub fn [<$a $b>](self) -> Vector<T, 2> {
+                Vector { values: [self.values[$ia], self.values[$ib]] }
+            }
+        )*}
+    };
+
+    (@three [$($a:ident $ia:tt)*] $all:tt) => {
+        $(swizzles!(@three_second $a $ia $all $all);)*
+    };
+    (@three_second $a:ident $ia:tt [$($b:ident $ib:tt)*] $all:tt) => {
+        $(swizzles!(@three_last $a $ia $b $ib $all);)*
+    };
+    (@three_last $a:ident $ia:tt $b:ident $ib:tt [$($c:ident $ic:tt)*]) => {
+        paste! {$(
+            #[inline]
+            pub fn [<$a $b $c>](self) -> Vector<T, 3> {
+                Vector { values: [self.values[$ia], self.values[$ib], self.values[$ic]] }
+            }
+        )*}
+    };
+
+    (@four [$($a:ident $ia:tt)*] $all:tt) => {
+        $(swizzles!(@four_second $a $ia $all $all);)*
+    };
+    (@four_second $a:ident $ia:tt [$($b:ident $ib:tt)*] $all:tt) => {
+        $(swizzles!(@four_third $a $ia $b $ib $all $all);)*
+    };
+    (@four_third $a:ident $ia:tt $b:ident $ib:tt [$($c:ident $ic:tt)*] $all:tt) => {
+        $(swizzles!(@four_last $a $ia $b $ib $c $ic $all);)*
+    };
+    (@four_last $a:ident $ia:tt $b:ident $ib:tt $c:ident $ic:tt [$($d:ident $id:tt)*]) => {
+        paste! {$(
+            #[inline]
+            pub fn [<$a $b $c $d>](self) -> Vector<T, 4> {
+                Vector { values: [self.values[$ia], self.values[$ib], self.values[$ic], self.values[$id]] }
+            }
+        )*}
+    };
+}
+
+swizzles!(2: x 0 y 1);
+swizzles!(2: r 0 g 1);
+swizzles!(3: x 0 y 1 z 2);
+swizzles!(3: r 0 g 1 b 2);
+swizzles!(4: x 0 y 1 z 2 w 3);
+swizzles!(4: r 0 g 1 b 2 a 3);
@@ -0,0 +1,136 @@
+//=============================================================================
+// transform.rs
+// Translation, rotation and scale of an object
+//=============================================================================
+
+use core::ops::Mul;
+use crate::math::matrix::Matrix4;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::Vector3;
+
+/// The position, rotation and scale of an object.
+/// The matrix is only built when it is requested
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    translation: Vector3,
+    rotation: Quaternion,
+    scale: Vector3,
+}
+
+impl Transform {
+    /// Combines a parent and a child transform into the child's world transform.
+    /// Exact as long as the parent has a uniform scale, otherwise the shear that
+    /// a rotated child would get is dropped
+    pub fn combine(parent: &Transform, child: &Transform) -> Transform {
+        Transform::new(
+            parent.transform_point(child.translation),
+            parent.rotation * child.rotation,
+            parent.scale * child.scale,
+        )
+    }
+
+    /// Creates a transform that only translates
+    pub fn from_translation(translation: Vector3) -> Transform {
+        Transform::new(translation, Quaternion::identity(), Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    /// Returns the transform that does nothing
+    pub fn identity() -> Transform {
+        Transform::from_translation(Vector3::new(0.0, 0.0, 0.0))
+    }
+
+    /// Returns the transform that undoes this one, see `combine` for
+    /// the limitations of non-uniform scale
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.inverse();
+        let scale = self.inverse_scale();
+        Transform::new(scale * (rotation * -self.translation), rotation, scale)
+    }
+
+    /// Returns the reciprocal of the scale, with zero for a flattened axis
+    fn inverse_scale(&self) -> Vector3 {
+        self.scale.map(|s| if s != 0.0 { 1.0 / s } else { 0.0 })
+    }
+
+    /// Transforms a direction from world space into local space, ignoring translation
+    pub fn inverse_transform_direction(&self, direction: Vector3) -> Vector3 {
+        (self.rotation.inverse() * direction) * self.inverse_scale()
+    }
+
+    /// Transforms a point from world space into local space
+    pub fn inverse_transform_point(&self, point: Vector3) -> Vector3 {
+        self.inverse_transform_direction(point - self.translation)
+    }
+
+    /// Returns the matrix that scales, then rotates, then translates
+    pub fn matrix(&self) -> Matrix4 {
+        Matrix4::trs(self.translation, self.rotation, self.scale)
+    }
+
+    /// Creates a new transform
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform { translation, rotation, scale }
+    }
+
+    /// Returns the rotation
+    pub fn rotation(&self) -> Quaternion {
+        self.rotation
+    }
+
+    /// Returns the scale
+    pub fn scale(&self) -> Vector3 {
+        self.scale
+    }
+
+    /// Changes the rotation
+    pub fn set_rotation(&mut self, rotation: Quaternion) {
+        self.rotation = rotation;
+    }
+
+    /// Changes the scale
+    pub fn set_scale(&mut self, scale: Vector3) {
+        self.scale = scale;
+    }
+
+    /// Changes the translation
+    pub fn set_translation(&mut self, translation: Vector3) {
+        self.translation = translation;
+    }
+
+    /// Transforms a direction from local space into world space, ignoring translation
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.rotation * (direction * self.scale)
+    }
+
+    /// Transforms a point from local space into world space
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.translation + self.transform_direction(point)
+    }
+
+    /// Returns the translation
+    pub fn translation(&self) -> Vector3 {
+        self.translation
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+impl From<&Transform> for Matrix4 {
+    fn from(transform: &Transform) -> Self {
+        transform.matrix()
+    }
+}
+
+/// Combines two transforms, see `Transform::combine`
+impl Mul<&Transform> for &Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: &Transform) -> Self::Output {
+        Transform::combine(self, rhs)
+    }
+}
@@ -4,901 +4,629 @@
 // Created by Victor on 2019/10/31
 //=============================================================================
 
-// TODO: Please for the love of god can we make this generics in the future?
-// I (Luuk) will gladly do it for you if you want.
-
-use std::ops::{Add, Div, Mul, Neg, Sub};
-use crate::math::{EPSILON, clamp};
-use std::fmt::{Display, Formatter, Error};
-
-/// A two dimensional vector of integers
-#[derive(Copy, Clone)]
-pub struct IVector2 {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl IVector2 {
-    pub fn new(x_val: i32, y_val: i32) -> IVector2 {
-        IVector2 {x: x_val, y: y_val }
-    }
-}
-
-/// A two dimensional vector of unsigned integers
-#[derive(Copy, Clone)]
-pub struct UVector2 {
-    pub x: u32,
-    pub y: u32,
+use core::convert::TryFrom;
+use core::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::fmt::{Display, Formatter, Error};
+use crate::math::angle::Degrees;
+use crate::math::fixed::Fixed;
+use crate::math::float::FloatMath;
+use crate::math::scalar::{Float, Scalar};
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use crate::math::simd;
+
+/// An N-dimensional vector
+///
+/// Every vector in the engine is an instance of this type. The components are
+/// reachable by name (`v.x`, `v.y`, ...) for two, three and four dimensions.
+///
+/// The old `Vector2`, `Vector3` and `IVector3` structs are now aliases of this
+/// type, so struct literals such as `Vector2 { x: 1.0, y: 2.0 }` no longer
+/// compile. Use `Vector2::new(1.0, 2.0)` or `Vector2::from([1.0, 2.0])` instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Vector<T, const N: usize> {
+    pub values: [T; N],
 }
 
-impl UVector2 {
-    pub fn new(x_val: u32, y_val: u32) -> UVector2 {
-        UVector2 {x: x_val, y: y_val }
-    }
-}
-
-// TODO: Do we need to implement copy and clone ourselves? ANSWER: Yes
 /// A two dimensional vector
-#[derive(Copy, Clone)]
-pub struct Vector2 {
-    pub x: f32,
-    pub y: f32,
-}
-
-impl Vector2 {
-    /// Returns the angle in degrees between two vectors
-    pub fn angle(from: Vector2, to: Vector2) -> f32 {
-        let denominator = (from.square_magnitude() * to.square_magnitude()).sqrt();
-        let dot = clamp(Vector2::dot(from, to) / denominator, -1.0, 1.0);
-        dot.acos().to_degrees()
-    }
-
-    /// Returns the distance between a and b
-    pub fn distance(a: Vector2, b: Vector2) -> f32 {
-        let x_diff = a.x - b.x;
-        let y_diff = a.x - b.x;
-        (x_diff * x_diff + y_diff * y_diff).sqrt()
-    }
-
+pub type Vector2 = Vector<f32, 2>;
+/// A two dimensional vector of integers
+pub type IVector2 = Vector<i32, 2>;
+/// A two dimensional vector of unsigned integers
+pub type UVector2 = Vector<u32, 2>;
+/// A three dimensional vector
+pub type Vector3 = Vector<f32, 3>;
+/// A three dimensional vector of integers
+pub type IVector3 = Vector<i32, 3>;
+/// A three dimensional vector of unsigned integers
+pub type UVector3 = Vector<u32, 3>;
+/// A four dimensional vector
+pub type Vector4 = Vector<f32, 4>;
+/// A four dimensional vector of integers
+pub type IVector4 = Vector<i32, 4>;
+/// A four dimensional vector of unsigned integers
+pub type UVector4 = Vector<u32, 4>;
+/// A two dimensional vector of fixed-point numbers
+pub type FixedVector2 = Vector<Fixed, 2>;
+/// A three dimensional vector of fixed-point numbers
+pub type FixedVector3 = Vector<Fixed, 3>;
+/// A four dimensional vector of fixed-point numbers
+pub type FixedVector4 = Vector<Fixed, 4>;
+/// A two dimensional vector in double precision
+pub type DVector2 = Vector<f64, 2>;
+/// A three dimensional vector in double precision
+pub type DVector3 = Vector<f64, 3>;
+/// A four dimensional vector in double precision
+pub type DVector4 = Vector<f64, 4>;
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
     /// Computes the dot product of two vectors
-    pub fn dot(lhs: Vector2, rhs: Vector2) -> f32 {
-        lhs.x * rhs.x + lhs.y * rhs.y
-    }
-
-    /// Creates a new Vector2
-    #[inline]
-    pub fn new(x_val: f32, y_val: f32) -> Vector2 {
-        Vector2 { x: x_val, y: y_val }
-    }
-
-    /// Linearly interpolates between two vectors
-    pub fn lerp(a: Vector2, b: Vector2, t: f32) -> Vector2 {
-        Vector2 { x: a.x + (b.x - a.x) * t, y: a.y + (b.y - a.y) * t }
-    }
-
-    /// Returns the magnitude of the vector
-    pub fn magnitude(self) -> f32 {
-        self.square_magnitude().sqrt()
-    }
-
-    /// Moves a point "current" toward "target"
-    pub fn move_toward(current: Vector2, target: Vector2, max_dist: f32) -> Vector2 {
-        let to_x = target.x - current.x;
-        let to_y = target.y - current.y;
-        let sqr_dist = to_x * to_x + to_y * to_y;
-
-        if sqr_dist == 0.0 || (max_dist >= 0.0 && sqr_dist <= max_dist * max_dist) {
-            return target;
+    pub fn dot(lhs: Self, rhs: Self) -> T {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(sum) = simd::dot4(lhs.values, rhs.values) {
+                return sum;
+            }
         }
 
-        let dist = sqr_dist.sqrt();
-        Vector2 { x: current.x + to_x / dist * max_dist, y: current.y + to_y / dist * max_dist }
+        T::dot(lhs, rhs)
     }
 
-    /// Makes this vector have a magnitude of 1
-    pub fn normalize(mut self) {
-        let mag = self.magnitude();
-        if mag > EPSILON {
-            self.x /= mag;
-            self.y /= mag;
-        } else {
-            self.x = 0.0;
-            self.y = 0.0;
-        }
+    /// Applies a function to every component of the vector
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vector<U, N> {
+        Vector { values: core::array::from_fn(|i| f(self.values[i])) }
     }
 
-    /// Returns this vector with a magnitude of 1
-    pub fn normalized(self) -> Vector2 {
-        let v = self.clone();
-        v.normalize();
-        v
-    }
-
-    /// Returns a vector2 perpendicular to the provided
-    pub fn perpendicular(in_dir: Vector2) -> Vector2 {
-        Vector2 { x: -in_dir.y, y: in_dir.x }
+    /// Returns the largest of each pair of components
+    pub fn max(a: Self, b: Self) -> Self {
+        Self::zip(a, b, |a, b| if b > a { b } else { a })
     }
 
-    /// Reflects a vector off the provided normal
-    pub fn reflect(in_dir: Vector2, in_norm: Vector2) -> Vector2 {
-        let factor = -2.0 * Vector2::dot(in_norm, in_dir);
-        Vector2 { x: factor * in_norm.x + in_dir.x, y: factor * in_norm.y * in_dir.y }
+    /// Returns the smallest of each pair of components
+    pub fn min(a: Self, b: Self) -> Self {
+        Self::zip(a, b, |a, b| if b < a { b } else { a })
     }
 
     /// Multiplies this vector component-wise by another vector
-    pub fn scale(mut self, amount: Vector2) {
-        self.x *= amount.x;
-        self.y *= amount.y;
-    }
-
-    /// Returns the signed angle between two vectors.
-    /// Always returns the smallest possible angle
-    pub fn signed_angle(from: Vector2, to: Vector2) -> f32 {
-        let unsigned_angle = Vector2::angle(from, to);
-        if (from.x * to.y - from.y * to.x).is_sign_positive() {
-            unsigned_angle
-        } else {
-            -unsigned_angle
-        }
+    pub fn scale(&mut self, amount: Self) {
+        *self *= amount;
     }
 
     /// Returns the square magnitude of the vector
-    pub fn square_magnitude(self) -> f32 {
-        self.x * self.x + self.y * self.y
+    pub fn square_magnitude(self) -> T {
+        Self::dot(self, self)
     }
-}
 
-/// A three dimensional vector
-#[derive(Copy, Clone)]
-pub struct Vector3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
-
-impl Vector3 {
-    /// Computes the cross product of two vectors
-    pub fn cross(lhs: Vector3, rhs: Vector3) -> Vector3 {
-        Vector3 { x: lhs.y * rhs.z - lhs.z * rhs.y, y: lhs.z * rhs.x - lhs.x * rhs.z, z: lhs.x * rhs.y - lhs.y * rhs.x }
+    /// Combines two vectors component-wise
+    pub fn zip<F: Fn(T, T) -> T>(a: Self, b: Self, f: F) -> Self {
+        Vector { values: core::array::from_fn(|i| f(a.values[i], b.values[i])) }
     }
-
-    /// Computes the dot product of two vectors
-    pub fn dot(lhs: Vector3, rhs: Vector3) -> f32 {
-        lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z
-    }
-
-    /// Creates a new Vector3
-    #[inline]
-    pub fn new(x_val: f32, y_val: f32, z_val: f32) -> Vector3 {
-        Vector3 { x: x_val, y: y_val, z: z_val }
-    }
-}
-
-/// A three-dimensional vector of integers
-#[derive(Copy, Clone)]
-pub struct IVector3 {
-    pub x: i32,
-    pub y: i32,
-    pub z: i32,
 }
 
-impl IVector3 {
-    /// Creates a new three-dimensional vector
-    pub fn new(x_val: i32, y_val: i32, z_val: i32) -> IVector3 {
-        IVector3 { x: x_val, y: y_val, z: z_val }
+impl<T: Scalar + Neg<Output = T>, const N: usize> Vector<T, N> {
+    /// Returns the absolute value of every component.
+    ///
+    /// Like `i32::abs`, a component of `i32::MIN` overflows: it panics in debug
+    /// builds and stays `i32::MIN` in release builds
+    pub fn abs(self) -> Self {
+        self.map(|a| if a < T::ZERO { -a } else { a })
     }
 }
 
-/// A three-dimensional vector of unsigned integers
-#[derive(Copy, Clone)]
-pub struct UVector3 {
-    pub x: u32,
-    pub y: u32,
-    pub z: u32,
-}
-
-impl UVector3 {
-    /// Creates a new three-dimensional vector
-    pub fn new(x_val: u32, y_val: u32, z_val: u32) -> UVector3 {
-        UVector3 { x: x_val, y: y_val, z: z_val }
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the angle between two vectors
+    pub fn angle(from: Self, to: Self) -> Degrees<T> {
+        let denominator = (from.square_magnitude() * to.square_magnitude()).sqrt();
+        let mut dot = Self::dot(from, to) / denominator;
+        if dot > T::ONE {
+            dot = T::ONE;
+        } else if dot < -T::ONE {
+            dot = -T::ONE;
+        }
+        Degrees(dot.acos().to_degrees())
     }
-}
 
-// A four dimensional vector
-#[derive(Copy, Clone)]
-pub struct Vector4 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
-}
+    /// Rounds every component up
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
 
-impl Vector4 {
-    /// Returns the distance between two vectors
-    pub fn distance(a: Vector4, b: Vector4) -> f32 {
-        Vector4::magnitude(a - b)
+    /// Returns the distance between a and b
+    pub fn distance(a: Self, b: Self) -> T {
+        (a - b).magnitude()
     }
 
-    /// Computes the dot product of two vectors
-    pub fn dot(a: Vector4, b: Vector4) -> f32 {
-        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    /// Rounds every component down
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
     }
 
     /// Linearly interpolates between two vectors
-    pub fn lerp(a: Vector4, b: Vector4, t: f32) -> Vector4 {
-        Vector4 {
-            x: a.x + (b.x - a.x) * t,
-            y: a.y + (b.y - a.y) * t,
-            z: a.z + (b.z - a.z) * t,
-            w: a.w + (b.w - a.w) * t,
-        }
+    pub fn lerp(a: Self, b: Self, t: T) -> Self {
+        a + (b - a) * t
     }
 
     /// Returns the magnitude of the vector
-    pub fn magnitude(self) -> f32 {
-        Vector4::dot(self, self).sqrt()
+    pub fn magnitude(self) -> T {
+        T::magnitude(self)
     }
 
-    /// Moves point "current" toward "target"
-    pub fn move_toward(current: Vector4, target: Vector4, max_dist: f32) -> Vector4 {
-        let to_x = target.x - current.x;
-        let to_y = target.y - current.y;
-        let to_z = target.z - current.z;
-        let to_w = target.w - current.w;
+    /// Moves a point "current" toward "target"
+    pub fn move_toward(current: Self, target: Self, max_dist: T) -> Self {
+        let to = target - current;
+        let sqr_dist = to.square_magnitude();
 
-        let sqr_dist = to_x * to_x + to_y * to_y + to_z * to_z + to_w * to_w;
-        if sqr_dist == 0.0 || (max_dist >= 0.0 && sqr_dist <= max_dist * max_dist) {
+        if sqr_dist == T::ZERO || (max_dist >= T::ZERO && sqr_dist <= max_dist * max_dist) {
             return target;
         }
 
-        let dist = sqr_dist.sqrt();
-        Vector4 {
-            x: current.x + target.x / dist * max_dist,
-            y: current.y + target.y / dist * max_dist,
-            z: current.z + target.z / dist * max_dist,
-            w: current.w + target.w / dist * max_dist,
-        }
+        current + to / sqr_dist.sqrt() * max_dist
     }
 
-    /// Creates a new Vector4
-    #[inline]
-    pub fn new(x_val: f32, y_val: f32, z_val: f32, w_val: f32) -> Vector4 {
-        Vector4 { x: x_val, y: y_val, z: z_val, w: w_val }
+    /// Makes this vector have a magnitude of 1
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
     }
 
-    /// Makes this vector have a magnitude of 1
-    pub fn normalize(mut self) {
+    /// Returns this vector with a magnitude of 1
+    pub fn normalized(self) -> Self {
         let mag = self.magnitude();
-        if mag > EPSILON {
-            self.x /= mag;
-            self.y /= mag;
-            self.z /= mag;
-            self.w /= mag;
+        if mag > T::EPSILON {
+            self / mag
         } else {
-            self.x = 0.0;
-            self.y = 0.0;
-            self.z = 0.0;
-            self.w = 0.0;
+            self.map(|_| T::ZERO)
         }
     }
 
-    /// Returns this vector with a magnitude of 1
-    pub fn normalized(self) -> Vector4 {
-        let v = self.clone();
-        v.normalize();
-        v
-    }
-
     /// Projects vector "a" onto "b"
-    pub fn project(a: Vector4, b: Vector4) -> Vector4 {
-        b * Vector4::dot(a, b) / Vector4::dot(b, b)
-    }
-
-    /// Multiplies this vector component-wise by another vector
-    pub fn scale(mut self, amount: Vector4) {
-        self.x *= amount.x;
-        self.y *= amount.y;
-        self.z *= amount.z;
-        self.w *= amount.w;
-    }
-
-    /// Returns the square magnitude of the vector
-    pub fn square_magnitude(self) -> f32 {
-        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
-    }
-}
-
-// A four dimensional vector of integers
-#[derive(Copy, Clone)]
-pub struct IVector4 {
-    pub x: i32,
-    pub y: i32,
-    pub z: i32,
-    pub w: i32,
-}
-
-impl IVector4 {
-    pub fn new(x_val: i32, y_val: i32, z_val: i32, w_val: i32) -> IVector4 {
-        IVector4 { x: x_val, y: y_val, z: z_val, w: w_val }
-    }
-}
-
-// A four dimensional vector of unsigned integers
-#[derive(Copy, Clone)]
-pub struct UVector4 {
-    pub x: u32,
-    pub y: u32,
-    pub z: u32,
-    pub w: u32,
-}
-
-impl UVector4 {
-    pub fn new(x_val: u32, y_val: u32, z_val: u32, w_val: u32) -> UVector4 {
-        UVector4 { x: x_val, y: y_val, z: z_val, w: w_val }
-    }
-}
-
-impl Display for Vector2 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl Display for IVector2 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl Display for UVector2 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl Display for Vector3 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
-    }
-}
-
-impl Display for IVector3 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "(i{}, i{}, i{})", self.x, self.y, self.z)
-    }
-}
-
-impl Display for UVector3 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "(i{}, i{}, i{})", self.x, self.y, self.z)
-    }
-}
-
-impl Display for Vector4 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
-    }
-}
-
-impl Display for IVector4 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
-    }
-}
-
-impl Display for UVector4 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
-    }
-}
-
-impl PartialEq for Vector2 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y
-    }
-}
-
-impl PartialEq for IVector2 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y
-    }
-}
-
-impl PartialEq for UVector2 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y
-    }
-}
-
-impl PartialEq for Vector3 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z
-    }
-}
-
-impl PartialEq for IVector3 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z
+    pub fn project(a: Self, b: Self) -> Self {
+        b * (Self::dot(a, b) / Self::dot(b, b))
     }
-}
 
-impl PartialEq for UVector3 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z
+    /// Reflects a vector off the provided normal
+    pub fn reflect(in_dir: Self, in_norm: Self) -> Self {
+        let factor = -(T::ONE + T::ONE) * Self::dot(in_norm, in_dir);
+        in_norm * factor + in_dir
     }
-}
 
-impl PartialEq for Vector4 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    /// Rounds every component to the nearest integer, half-way cases away from zero
+    pub fn round(self) -> Self {
+        self.map(T::round)
     }
 }
 
-impl PartialEq for IVector4 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+impl<T> Vector<T, 2> {
+    /// Creates a new two dimensional vector
+    #[inline]
+    pub const fn new(x_val: T, y_val: T) -> Self {
+        Vector { values: [x_val, y_val] }
     }
-}
 
-impl PartialEq for UVector4 {
-    fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    /// Creates a three dimensional vector by adding a z component
+    #[inline]
+    pub fn extend(self, z_val: T) -> Vector<T, 3> where T: Copy {
+        Vector::<T, 3>::new(self.x, self.y, z_val)
     }
 }
 
-// Operator implementations
-impl Add<Vector2> for Vector2 {
-    type Output = Vector2;
-
-    fn add(self, rhs: Vector2) -> Self::Output {
-        Vector2 { x: self.x + rhs.x, y: self.y + rhs.y }
+impl<T: Float> Vector<T, 2> {
+    /// Returns a vector perpendicular to the provided
+    pub fn perpendicular(in_dir: Self) -> Self {
+        Self::new(-in_dir.y, in_dir.x)
     }
-}
-
-impl Add<IVector2> for IVector2 {
-    type Output = IVector2;
 
-    fn add(self, rhs: IVector2) -> Self::Output {
-        IVector2 { x: self.x + rhs.x, y: self.y + rhs.y }
+    /// Returns the signed angle between two vectors.
+    /// Always returns the smallest possible angle
+    pub fn signed_angle(from: Self, to: Self) -> Degrees<T> {
+        let unsigned_angle = Self::angle(from, to);
+        if from.x * to.y - from.y * to.x >= T::ZERO {
+            unsigned_angle
+        } else {
+            -unsigned_angle
+        }
     }
 }
 
-impl Add<UVector2> for UVector2 {
-    type Output = UVector2;
-
-    fn add(self, rhs: UVector2) -> Self::Output {
-        UVector2 { x: self.x + rhs.x, y: self.y + rhs.y }
+impl<T> Vector<T, 3> {
+    /// Creates a new three dimensional vector
+    #[inline]
+    pub const fn new(x_val: T, y_val: T, z_val: T) -> Self {
+        Vector { values: [x_val, y_val, z_val] }
     }
-}
 
-impl Add<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn add(self, rhs: Vector3) -> Self::Output {
-        Vector3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    /// Creates a four dimensional vector by adding a w component
+    #[inline]
+    pub fn extend(self, w_val: T) -> Vector<T, 4> where T: Copy {
+        Vector::<T, 4>::new(self.x, self.y, self.z, w_val)
     }
-}
-
-impl Add<IVector3> for IVector3 {
-    type Output = IVector3;
 
-    fn add(self, rhs: IVector3) -> Self::Output {
-        IVector3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    /// Creates a two dimensional vector by dropping the z component
+    #[inline]
+    pub fn truncate(self) -> Vector<T, 2> where T: Copy {
+        Vector::<T, 2>::new(self.x, self.y)
     }
 }
 
-impl Add<UVector3> for UVector3 {
-    type Output = UVector3;
-
-    fn add(self, rhs: UVector3) -> Self::Output {
-        UVector3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+impl<T: Scalar> Vector<T, 3> {
+    /// Computes the cross product of two vectors
+    pub fn cross(lhs: Self, rhs: Self) -> Self {
+        Self::new(
+            lhs.y * rhs.z - lhs.z * rhs.y,
+            lhs.z * rhs.x - lhs.x * rhs.z,
+            lhs.x * rhs.y - lhs.y * rhs.x,
+        )
     }
 }
 
-impl Add<Vector4> for Vector4 {
-    type Output = Vector4;
-
-    fn add(self, rhs: Vector4) -> Self::Output {
-        Vector4 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+impl<T> Vector<T, 4> {
+    /// Creates a new four dimensional vector
+    #[inline]
+    pub const fn new(x_val: T, y_val: T, z_val: T, w_val: T) -> Self {
+        Vector { values: [x_val, y_val, z_val, w_val] }
     }
-}
-
-impl Add<IVector4> for IVector4 {
-    type Output = IVector4;
 
-    fn add(self, rhs: IVector4) -> Self::Output {
-        IVector4 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+    /// Creates a three dimensional vector by dropping the w component
+    #[inline]
+    pub fn truncate(self) -> Vector<T, 3> where T: Copy {
+        Vector::<T, 3>::new(self.x, self.y, self.z)
     }
 }
 
-impl Add<UVector4> for UVector4 {
-    type Output = UVector4;
-
-    fn add(self, rhs: UVector4) -> Self::Output {
-        UVector4 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+/// The zero vector, for integer vectors too
+impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Vector { values: [T::default(); N] }
     }
 }
 
-impl Div<Vector2> for Vector2 {
-    type Output = Vector2;
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
 
-    fn div(self, rhs: Vector2) -> Self::Output {
-        Vector2 { x: self.x / rhs.x, y: self.y / rhs.y }
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
     }
 }
 
-impl Div<f32> for Vector2 {
-    type Output = Vector2;
-
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector2 { x: self.x / rhs, y: self.y / rhs }
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
     }
 }
 
-impl Div<IVector2> for IVector2 {
-    type Output = IVector2;
-
-    fn div(self, rhs: IVector2) -> Self::Output {
-        IVector2 { x: self.x / rhs.x, y: self.y / rhs.y }
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(values: [T; N]) -> Self {
+        Vector { values }
     }
 }
 
-impl Div<i32> for IVector2 {
-    type Output = IVector2;
-
-    fn div(self, rhs: i32) -> Self::Output {
-        IVector2 { x: self.x / rhs, y: self.y / rhs }
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.values
     }
 }
 
-impl Div<UVector2> for UVector2 {
-    type Output = UVector2;
-
-    fn div(self, rhs: UVector2) -> Self::Output {
-        UVector2 { x: self.x / rhs.x, y: self.y / rhs.y }
+impl<T> From<(T, T)> for Vector<T, 2> {
+    fn from((x, y): (T, T)) -> Self {
+        Vector { values: [x, y] }
     }
 }
 
-impl Div<u32> for UVector2 {
-    type Output = UVector2;
-
-    fn div(self, rhs: u32) -> Self::Output {
-        UVector2 { x: self.x / rhs, y: self.y / rhs }
+impl<T> From<(T, T, T)> for Vector<T, 3> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vector { values: [x, y, z] }
     }
 }
 
-impl Div<f32> for Vector3 {
-    type Output = Vector3;
-
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector3 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+impl<T> From<(T, T, T, T)> for Vector<T, 4> {
+    fn from((x, y, z, w): (T, T, T, T)) -> Self {
+        Vector { values: [x, y, z, w] }
     }
 }
 
-impl Div<i32> for IVector3 {
-    type Output = IVector3;
-
-    fn div(self, rhs: i32) -> Self::Output {
-        IVector3 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+impl<T> From<Vector<T, 2>> for (T, T) {
+    fn from(vector: Vector<T, 2>) -> Self {
+        let [x, y] = vector.values;
+        (x, y)
     }
 }
 
-impl Div<u32> for UVector3 {
-    type Output = UVector3;
-
-    fn div(self, rhs: u32) -> Self::Output {
-        UVector3 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+impl<T> From<Vector<T, 3>> for (T, T, T) {
+    fn from(vector: Vector<T, 3>) -> Self {
+        let [x, y, z] = vector.values;
+        (x, y, z)
     }
 }
 
-impl Div<f32> for Vector4 {
-    type Output = Vector4;
-
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector4 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs, w: self.w / rhs }
+impl<T> From<Vector<T, 4>> for (T, T, T, T) {
+    fn from(vector: Vector<T, 4>) -> Self {
+        let [x, y, z, w] = vector.values;
+        (x, y, z, w)
     }
 }
 
-impl Div<i32> for IVector4 {
-    type Output = IVector4;
+/// The error of a vector conversion where a component doesn't fit in the new type
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TryFromVectorError(());
 
-    fn div(self, rhs: i32) -> Self::Output {
-        IVector4 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs, w: self.w / rhs }
+impl Display for TryFromVectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "a component is out of range for the target type")
     }
 }
 
-impl Div<u32> for UVector4 {
-    type Output = UVector4;
+impl core::error::Error for TryFromVectorError {}
 
-    fn div(self, rhs: u32) -> Self::Output {
-        UVector4 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs, w: self.w / rhs }
+/// Converts every component with `TryFrom`, failing if any component fails
+fn try_convert<T: Copy, U: Copy + Default, F: Fn(T) -> Option<U>, const N: usize>(
+    vector: Vector<T, N>,
+    f: F,
+) -> Result<Vector<U, N>, TryFromVectorError> {
+    let mut values = [U::default(); N];
+    for (value, &component) in values.iter_mut().zip(vector.values.iter()) {
+        *value = f(component).ok_or(TryFromVectorError(()))?;
     }
+    Ok(Vector { values })
 }
 
-impl Mul<Vector2> for Vector2 {
-    type Output = Vector2;
-
-    fn mul(self, rhs: Vector2) -> Self::Output {
-        Vector2 { x: self.x * rhs.x, y: self.y * rhs.y }
-    }
+macro_rules! impl_to_float {
+    ($($from:ty => $to:ty),*) => {$(
+        /// Converts to the nearest float, which is exact except for integers
+        /// above 2^24 becoming f32
+        impl<const N: usize> From<Vector<$from, N>> for Vector<$to, N> {
+            fn from(vector: Vector<$from, N>) -> Self {
+                vector.map(|a| a as $to)
+            }
+        }
+    )*};
 }
 
-impl Mul<f32> for Vector2 {
-    type Output = Vector2;
+impl_to_float!(i32 => f32, u32 => f32, i32 => f64, u32 => f64, f32 => f64);
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector2 { x: self.x * rhs, y: self.y * rhs }
+impl<const N: usize> Vector<f64, N> {
+    /// Converts into a single precision vector, for rendering. Rounds to the
+    /// nearest f32, so positions far from the origin lose their small digits
+    pub fn to_f32(self) -> Vector<f32, N> {
+        self.map(|a| a as f32)
     }
 }
 
-impl Mul<Vector2> for f32 {
-    type Output = Vector2;
+macro_rules! impl_try_int_to_int {
+    ($($from:ty => $to:ty),*) => {$(
+        impl<const N: usize> TryFrom<Vector<$from, N>> for Vector<$to, N> {
+            type Error = TryFromVectorError;
 
-    fn mul(self, rhs: Vector2) -> Self::Output {
-        Vector2 { x: self * rhs.x, y: self * rhs.y }
-    }
+            fn try_from(vector: Vector<$from, N>) -> Result<Self, Self::Error> {
+                try_convert(vector, |a| <$to>::try_from(a).ok())
+            }
+        }
+    )*};
 }
 
-impl Mul<IVector2> for IVector2 {
-    type Output = IVector2;
+impl_try_int_to_int!(i32 => u32, u32 => i32);
 
-    fn mul(self, rhs: IVector2) -> Self::Output {
-        IVector2 { x: self.x * rhs.x, y: self.y * rhs.y }
-    }
-}
-
-impl Mul<i32> for IVector2 {
-    type Output = IVector2;
+macro_rules! impl_try_float_to_int {
+    ($($from:ty => $to:ty),*) => {$(
+        /// Truncates toward zero like `as`, use `floor` or `round` first for other
+        /// rounding. Fails on NaN and on values outside of the integer range
+        impl<const N: usize> TryFrom<Vector<$from, N>> for Vector<$to, N> {
+            type Error = TryFromVectorError;
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        IVector2 { x: self.x * rhs, y: self.y * rhs }
-    }
+            fn try_from(vector: Vector<$from, N>) -> Result<Self, Self::Error> {
+                // MAX + 1 is a power of two, exact in the float type and an exclusive bound
+                let min = <$to>::MIN as $from;
+                let max = <$to>::MAX as $from + 1.0;
+                try_convert(vector, |a| if FloatMath::trunc(a) >= min && a < max { Some(a as $to) } else { None })
+            }
+        }
+    )*};
 }
 
-impl Mul<IVector2> for i32 {
-    type Output = IVector2;
+impl_try_float_to_int!(f32 => i32, f32 => u32, f64 => i32, f64 => u32);
 
-    fn mul(self, rhs: IVector2) -> Self::Output {
-        IVector2 { x: self * rhs.x, y: self * rhs.y }
+impl<T: Display, const N: usize> Display for Vector<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "(")?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, ")")
     }
 }
 
-impl Mul<UVector2> for UVector2 {
-    type Output = UVector2;
-
-    fn mul(self, rhs: UVector2) -> Self::Output {
-        UVector2 { x: self.x * rhs.x, y: self.y * rhs.y }
-    }
+// Named component access
+/// The components of a two dimensional vector
+#[repr(C)]
+pub struct Xy<T> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Mul<u32> for UVector2 {
-    type Output = UVector2;
-
-    fn mul(self, rhs: u32) -> Self::Output {
-        UVector2 { x: self.x * rhs, y: self.y * rhs }
-    }
+/// The components of a three dimensional vector
+#[repr(C)]
+pub struct Xyz<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Mul<UVector2> for u32 {
-    type Output = UVector2;
-
-    fn mul(self, rhs: UVector2) -> Self::Output {
-        UVector2 { x: self * rhs.x, y: self * rhs.y }
-    }
+/// The components of a four dimensional vector
+#[repr(C)]
+pub struct Xyzw<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
 }
 
-impl Mul<f32> for Vector3 {
-    type Output = Vector3;
+macro_rules! impl_components {
+    ($n:expr, $target:ident) => {
+        impl<T> Deref for Vector<T, $n> {
+            type Target = $target<T>;
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
-    }
-}
-
-impl Mul<Vector3> for f32 {
-    type Output = Vector3;
-
-    fn mul(self, rhs: Vector3) -> Self::Output {
-        Vector3 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z }
-    }
-}
-
-impl Mul<i32> for IVector3 {
-    type Output = IVector3;
+            fn deref(&self) -> &Self::Target {
+                // Both types are repr(C) and made of exactly $n values of T
+                unsafe { &*(self as *const Self as *const $target<T>) }
+            }
+        }
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        IVector3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
-    }
+        impl<T> DerefMut for Vector<T, $n> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                unsafe { &mut *(self as *mut Self as *mut $target<T>) }
+            }
+        }
+    };
 }
 
-impl Mul<IVector3> for i32 {
-    type Output = IVector3;
+impl_components!(2, Xy);
+impl_components!(3, Xyz);
+impl_components!(4, Xyzw);
 
-    fn mul(self, rhs: IVector3) -> Self::Output {
-        IVector3 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z }
-    }
-}
-
-impl Mul<u32> for UVector3 {
-    type Output = UVector3;
+// Operator implementations
+impl<T: Scalar, const N: usize> Add<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn mul(self, rhs: u32) -> Self::Output {
-        UVector3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    fn add(self, rhs: T) -> Self::Output {
+        self.map(|a| a + rhs)
     }
 }
 
-impl Mul<UVector3> for u32 {
-    type Output = UVector3;
+impl<T: Scalar, const N: usize> Add<Vector<T, N>> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn mul(self, rhs: UVector3) -> Self::Output {
-        UVector3 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z }
-    }
-}
-
-impl Mul<f32> for Vector4 {
-    type Output = Vector4;
+    fn add(self, rhs: Vector<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4(self.values, rhs.values, simd::Op::Add) {
+                return Vector { values };
+            }
+        }
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector4 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs }
+        Vector::zip(self, rhs, |a, b| a + b)
     }
 }
 
-impl Mul<Vector4> for f32 {
-    type Output = Vector4;
+impl<T: Scalar, const N: usize> Div<Vector<T, N>> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn mul(self, rhs: Vector4) -> Self::Output {
-        Vector4 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z, w: self * rhs.w }
-    }
-}
-
-impl Mul<i32> for IVector4 {
-    type Output = IVector4;
+    fn div(self, rhs: Vector<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4(self.values, rhs.values, simd::Op::Div) {
+                return Vector { values };
+            }
+        }
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        IVector4 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs }
+        Vector::zip(self, rhs, |a, b| a / b)
     }
 }
 
-impl Mul<IVector4> for i32 {
-    type Output = IVector4;
+impl<T: Scalar, const N: usize> Div<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn mul(self, rhs: IVector4) -> Self::Output {
-        IVector4 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z, w: self * rhs.w }
-    }
-}
-
-impl Mul<u32> for UVector4 {
-    type Output = UVector4;
+    fn div(self, rhs: T) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4_scalar(self.values, rhs, simd::Op::Div) {
+                return Vector { values };
+            }
+        }
 
-    fn mul(self, rhs: u32) -> Self::Output {
-        UVector4 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs }
+        self.map(|a| a / rhs)
     }
 }
 
-impl Mul<UVector4> for u32 {
-    type Output = UVector4;
+impl<T: Scalar, const N: usize> Mul<Vector<T, N>> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn mul(self, rhs: UVector4) -> Self::Output {
-        UVector4 { x: self * rhs.x, y: self * rhs.y, z: self * rhs.z, w: self * rhs.w }
-    }
-}
-
-impl Neg for Vector2 {
-    type Output = Vector2;
+    fn mul(self, rhs: Vector<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4(self.values, rhs.values, simd::Op::Mul) {
+                return Vector { values };
+            }
+        }
 
-    fn neg(self) -> Self::Output {
-        Vector2 { x: -self.x, y: -self.y }
+        Vector::zip(self, rhs, |a, b| a * b)
     }
 }
 
-impl Neg for IVector2 {
-    type Output = IVector2;
-
-    fn neg(self) -> Self::Output {
-        IVector2 { x: -self.x, y: -self.y }
-    }
-}
+impl<T: Scalar, const N: usize> Mul<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-impl Neg for Vector3 {
-    type Output = Vector3;
+    fn mul(self, rhs: T) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4_scalar(self.values, rhs, simd::Op::Mul) {
+                return Vector { values };
+            }
+        }
 
-    fn neg(self) -> Self::Output {
-        Vector3 { x: -self.x, y: -self.y, z: -self.z }
+        self.map(|a| a * rhs)
     }
 }
 
-impl Neg for IVector3 {
-    type Output = IVector3;
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl<const N: usize> Mul<Vector<$t, N>> for $t {
+            type Output = Vector<$t, N>;
 
-    fn neg(self) -> Self::Output {
-        IVector3 { x: -self.x, y: -self.y, z: -self.z }
-    }
+            fn mul(self, rhs: Vector<$t, N>) -> Self::Output {
+                rhs.map(|a| self * a)
+            }
+        }
+    )*};
 }
 
-impl Neg for Vector4 {
-    type Output = Vector4;
-
-    fn neg(self) -> Self::Output {
-        Vector4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
-    }
-}
+impl_scalar_mul!(f32, f64, i32, u32, Fixed);
 
-impl Neg for IVector4 {
-    type Output = IVector4;
+impl<T: Scalar + Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+    type Output = Vector<T, N>;
 
     fn neg(self) -> Self::Output {
-        IVector4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+        self.map(|a| -a)
     }
 }
 
-impl Sub<Vector2> for Vector2 {
-    type Output = Vector2;
+impl<T: Scalar, const N: usize> Sub<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn sub(self, rhs: Vector2) -> Self::Output {
-        Vector2 { x: self.x - rhs.x, y: self.y - rhs.y }
+    fn sub(self, rhs: T) -> Self::Output {
+        self.map(|a| a - rhs)
     }
 }
 
-impl Sub<IVector2> for IVector2 {
-    type Output = IVector2;
+impl<T: Scalar, const N: usize> Sub<Vector<T, N>> for Vector<T, N> {
+    type Output = Vector<T, N>;
 
-    fn sub(self, rhs: IVector2) -> Self::Output {
-        IVector2 { x: self.x - rhs.x, y: self.y - rhs.y }
-    }
-}
-
-impl Sub<UVector2> for UVector2 {
-    type Output = UVector2;
-
-    fn sub(self, rhs: UVector2) -> Self::Output {
-        UVector2 { x: self.x - rhs.x, y: self.y - rhs.y }
-    }
-}
-
-impl Sub<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn sub(self, rhs: Vector3) -> Self::Output {
-        Vector3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
-    }
-}
-
-impl Sub<IVector3> for IVector3 {
-    type Output = IVector3;
-
-    fn sub(self, rhs: IVector3) -> Self::Output {
-        IVector3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
-    }
-}
-
-impl Sub<UVector3> for UVector3 {
-    type Output = UVector3;
-
-    fn sub(self, rhs: UVector3) -> Self::Output {
-        UVector3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
-    }
-}
-
-impl Sub<Vector4> for Vector4 {
-    type Output = Vector4;
+    fn sub(self, rhs: Vector<T, N>) -> Self::Output {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if let Some(values) = simd::vector4(self.values, rhs.values, simd::Op::Sub) {
+                return Vector { values };
+            }
+        }
 
-    fn sub(self, rhs: Vector4) -> Self::Output {
-        Vector4 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
+        Vector::zip(self, rhs, |a, b| a - b)
     }
 }
 
-impl Sub<IVector4> for IVector4 {
-    type Output = IVector4;
+macro_rules! impl_assign {
+    ($($assign:ident, $assign_fn:ident, $op_fn:ident);*) => {$(
+        impl<T: Scalar, const N: usize> $assign<Vector<T, N>> for Vector<T, N> {
+            fn $assign_fn(&mut self, rhs: Vector<T, N>) {
+                *self = (*self).$op_fn(rhs);
+            }
+        }
 
-    fn sub(self, rhs: IVector4) -> Self::Output {
-        IVector4 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
-    }
+        impl<T: Scalar, const N: usize> $assign<T> for Vector<T, N> {
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = (*self).$op_fn(rhs);
+            }
+        }
+    )*};
 }
 
-impl Sub<UVector4> for UVector4 {
-    type Output = UVector4;
-
-    fn sub(self, rhs: UVector4) -> Self::Output {
-        UVector4 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
-    }
-}
+impl_assign!(AddAssign, add_assign, add; DivAssign, div_assign, div; MulAssign, mul_assign, mul; SubAssign, sub_assign, sub);
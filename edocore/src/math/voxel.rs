@@ -0,0 +1,151 @@
+//=============================================================================
+// voxel.rs
+// Index math for voxel grids split into chunks
+//
+// World coordinates are signed and unbounded. They split into the coordinate
+// of a chunk and an unsigned local coordinate inside it, rounding toward
+// negative infinity so that -1 lies in chunk -1 and not in chunk 0.
+//=============================================================================
+
+use crate::math::float::FloatMath;
+use crate::math::vector::{IVector3, UVector3, Vector3};
+
+/// Which voxels around a voxel count as its neighbours
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighbourhood {
+    /// The 6 voxels sharing a face
+    Faces,
+    /// The 18 voxels sharing a face or an edge
+    Edges,
+    /// The 26 voxels sharing a face, an edge or a corner
+    Corners,
+}
+
+impl Neighbourhood {
+    /// Returns the offsets to the neighbours, ordered by z, then y, then x
+    pub fn offsets(self) -> impl Iterator<Item = IVector3> {
+        let max_axes = match self {
+            Neighbourhood::Faces => 1,
+            Neighbourhood::Edges => 2,
+            Neighbourhood::Corners => 3,
+        };
+
+        (0..27).map(|i| IVector3::new(i % 3 - 1, i / 3 % 3 - 1, i / 9 - 1)).filter(move |offset| {
+            let axes = offset.values.iter().filter(|&&v| v != 0).count();
+            axes > 0 && axes <= max_axes
+        })
+    }
+}
+
+/// Returns the neighbours of "voxel", see `Neighbourhood::offsets` for the order
+pub fn neighbours(voxel: IVector3, neighbourhood: Neighbourhood) -> impl Iterator<Item = IVector3> {
+    neighbourhood.offsets().map(move |offset| voxel + offset)
+}
+
+/// Returns the coordinate of the chunk containing the voxel at "world"
+pub fn world_to_chunk(world: IVector3, chunk_size: UVector3) -> IVector3 {
+    IVector3::new(
+        world.x.div_euclid(chunk_size.x as i32),
+        world.y.div_euclid(chunk_size.y as i32),
+        world.z.div_euclid(chunk_size.z as i32),
+    )
+}
+
+/// Returns the coordinate of the voxel at "world" inside its chunk
+pub fn world_to_local(world: IVector3, chunk_size: UVector3) -> UVector3 {
+    UVector3::new(
+        world.x.rem_euclid(chunk_size.x as i32) as u32,
+        world.y.rem_euclid(chunk_size.y as i32) as u32,
+        world.z.rem_euclid(chunk_size.z as i32) as u32,
+    )
+}
+
+/// Returns the chunk and the local coordinate of the voxel at "world"
+pub fn world_to_chunk_local(world: IVector3, chunk_size: UVector3) -> (IVector3, UVector3) {
+    (world_to_chunk(world, chunk_size), world_to_local(world, chunk_size))
+}
+
+/// Returns the world coordinate of the voxel at "local" inside "chunk"
+pub fn chunk_local_to_world(chunk: IVector3, local: UVector3, chunk_size: UVector3) -> IVector3 {
+    IVector3::new(
+        chunk.x * chunk_size.x as i32 + local.x as i32,
+        chunk.y * chunk_size.y as i32 + local.y as i32,
+        chunk.z * chunk_size.z as i32 + local.z as i32,
+    )
+}
+
+/// Returns the voxel containing a point, voxels being cubes of size 1 at integer coordinates
+pub fn voxel_at(point: Vector3) -> IVector3 {
+    IVector3::new(FloatMath::floor(point.x) as i32, FloatMath::floor(point.y) as i32, FloatMath::floor(point.z) as i32)
+}
+
+/// Returns the index of "position" in an array of "size" voxels, x changing fastest
+pub fn linearize(position: UVector3, size: UVector3) -> usize {
+    position.x as usize + size.x as usize * (position.y as usize + size.y as usize * position.z as usize)
+}
+
+/// Returns the position of "index" in an array of "size" voxels, undoing `linearize`
+pub fn delinearize(index: usize, size: UVector3) -> UVector3 {
+    let (width, height) = (size.x as usize, size.y as usize);
+    UVector3::new((index % width) as u32, (index / width % height) as u32, (index / (width * height)) as u32)
+}
+
+/// Interleaves the lowest 10 bits of each component into a Z-order curve index,
+/// keeping voxels that are close in space close in memory
+pub fn morton_encode_u32(position: UVector3) -> u32 {
+    spread_u32(position.x) | spread_u32(position.y) << 1 | spread_u32(position.z) << 2
+}
+
+/// Returns the position of a Z-order curve index, undoing `morton_encode_u32`
+pub fn morton_decode_u32(code: u32) -> UVector3 {
+    UVector3::new(compact_u32(code), compact_u32(code >> 1), compact_u32(code >> 2))
+}
+
+/// Interleaves the lowest 21 bits of each component into a Z-order curve index
+pub fn morton_encode_u64(position: UVector3) -> u64 {
+    spread_u64(position.x) | spread_u64(position.y) << 1 | spread_u64(position.z) << 2
+}
+
+/// Returns the position of a Z-order curve index, undoing `morton_encode_u64`
+pub fn morton_decode_u64(code: u64) -> UVector3 {
+    UVector3::new(compact_u64(code), compact_u64(code >> 1), compact_u64(code >> 2))
+}
+
+/// Moves the lowest 10 bits of "value" to every third bit
+fn spread_u32(value: u32) -> u32 {
+    let mut x = value & 0x0000_03ff;
+    x = (x | x << 16) & 0x0300_00ff;
+    x = (x | x << 8) & 0x0300_f00f;
+    x = (x | x << 4) & 0x030c_30c3;
+    (x | x << 2) & 0x0924_9249
+}
+
+/// Gathers every third bit of "value", undoing `spread_u32`
+fn compact_u32(value: u32) -> u32 {
+    let mut x = value & 0x0924_9249;
+    x = (x | x >> 2) & 0x030c_30c3;
+    x = (x | x >> 4) & 0x0300_f00f;
+    x = (x | x >> 8) & 0x0300_00ff;
+    (x | x >> 16) & 0x0000_03ff
+}
+
+/// Moves the lowest 21 bits of "value" to every third bit
+fn spread_u64(value: u32) -> u64 {
+    let mut x = value as u64 & 0x001f_ffff;
+    x = (x | x << 32) & 0x001f_0000_0000_ffff;
+    x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+    x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+    x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+    (x | x << 2) & 0x1249_2492_4924_9249
+}
+
+/// Gathers every third bit of "value", undoing `spread_u64`
+fn compact_u64(value: u64) -> u32 {
+    let mut x = value & 0x1249_2492_4924_9249;
+    x = (x | x >> 2) & 0x10c3_0c30_c30c_30c3;
+    x = (x | x >> 4) & 0x100f_00f0_0f00_f00f;
+    x = (x | x >> 8) & 0x001f_0000_ff00_00ff;
+    x = (x | x >> 16) & 0x001f_0000_0000_ffff;
+    ((x | x >> 32) & 0x001f_ffff) as u32
+}
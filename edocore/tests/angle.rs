@@ -0,0 +1,58 @@
+use edocore::{assert_approx_eq, assert_relative_eq};
+use edocore::math::angle::{Degrees, Radians};
+use edocore::math::matrix::Matrix2;
+use edocore::math::quaternion::Quaternion;
+use edocore::math::vector::{Vector2, Vector3};
+
+#[test]
+fn test_conversions() {
+    assert_relative_eq!(Radians::from(Degrees(180.0)), Radians(std::f32::consts::PI));
+    assert_relative_eq!(Degrees::from(Radians(std::f32::consts::FRAC_PI_2)), Degrees(90.0));
+    assert_relative_eq!(Degrees::<f64>::from(Radians::from(Degrees(-45.0))), Degrees(-45.0));
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut a = Degrees(30.0);
+    a += Degrees(15.0);
+    a *= 2.0;
+
+    assert_eq!(a, Degrees(90.0));
+    assert_eq!(a - Degrees(100.0), Degrees(-10.0));
+    assert_eq!(-a / 3.0, Degrees(-30.0));
+    assert_eq!(Degrees(360.0) / a, 4.0);
+    assert_relative_eq!(Degrees(30.0).sin(), 0.5);
+    assert_relative_eq!(Radians(0.0).cos(), 1.0);
+}
+
+#[test]
+fn test_wrap() {
+    assert_relative_eq!(Degrees(190.0).wrap_pi(), Degrees(-170.0));
+    assert_relative_eq!(Degrees(-180.0).wrap_pi(), Degrees(180.0));
+    assert_relative_eq!(Degrees(720.0 + 45.0).wrap_pi(), Degrees(45.0));
+    assert_relative_eq!(Degrees(-90.0).wrap_two_pi(), Degrees(270.0));
+    assert_relative_eq!(Radians(-std::f64::consts::PI * 3.0).wrap_two_pi(), Radians(std::f64::consts::PI));
+    assert!(Radians(-1e-9_f32).wrap_two_pi().0 < std::f32::consts::PI * 2.0);
+}
+
+#[test]
+fn test_shortest_delta() {
+    assert_relative_eq!(Degrees::shortest_delta(Degrees(350.0), Degrees(10.0)), Degrees(20.0));
+    assert_relative_eq!(Degrees::shortest_delta(Degrees(10.0), Degrees(350.0)), Degrees(-20.0));
+    assert_relative_eq!(Radians::shortest_delta(Radians(0.0), Radians(3.0)), Radians(3.0));
+}
+
+#[test]
+fn test_either_unit() {
+    let axis = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_approx_eq!(Quaternion::axis_angle(axis, Degrees(90.0)), Quaternion::axis_angle(axis, Radians(std::f32::consts::FRAC_PI_2)));
+    assert_approx_eq!(Matrix2::from_angle(Radians(std::f32::consts::PI)) * Vector2::new(1.0, 0.0), Vector2::new(-1.0, 0.0));
+    assert_relative_eq!(Quaternion::angle(Quaternion::identity(), Quaternion::euler(Radians(0.5), Degrees(0.0), Radians(0.0))), Radians(0.5).into());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Degrees(90.0).to_string(), "90°");
+    assert_eq!(Radians(1.5).to_string(), "1.5 rad");
+}
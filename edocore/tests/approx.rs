@@ -0,0 +1,67 @@
+use edocore::{assert_approx_eq, assert_relative_eq, assert_ulps_eq};
+use edocore::math::approx::ApproxEq;
+use edocore::math::fixed::Fixed;
+use edocore::math::matrix::Matrix4;
+use edocore::math::vector::{FixedVector2, Vector2, Vector3};
+
+#[test]
+fn test_abs_diff_eq() {
+    assert!(1.0f32.abs_diff_eq(&1.05, 0.1));
+    assert!(!1.0f32.abs_diff_eq(&1.2, 0.1));
+    assert!(f32::INFINITY.abs_diff_eq(&f32::INFINITY, 0.0));
+    assert!(!f32::NAN.abs_diff_eq(&f32::NAN, 1.0));
+    assert!(Vector3::new(1.0, 2.0, 3.0).approx_eq(&Vector3::new(1.0, 2.000001, 3.0)));
+}
+
+#[test]
+fn test_relative_eq() {
+    assert!(1000.0f32.relative_eq(&1000.05, 0.0, 0.0001));
+    assert!(!1000.0f32.relative_eq(&1001.0, 0.0, 0.0001));
+    assert!(!0.001f32.relative_eq(&0.002, 0.0, 0.1));
+    assert!(!f32::INFINITY.relative_eq(&f32::MAX, 0.0, 1.0));
+}
+
+#[test]
+fn test_ulps_eq() {
+    let next = f32::from_bits(1.0f32.to_bits() + 2);
+
+    assert!(1.0f32.ulps_eq(&next, 0.0, 2));
+    assert!(!1.0f32.ulps_eq(&next, 0.0, 1));
+    assert!(!1.0f32.ulps_eq(&-1.0, 0.0, 4));
+    assert!(0.0f32.ulps_eq(&-0.0, 0.0, 0));
+    assert!(1.0f64.ulps_eq(&f64::from_bits(1.0f64.to_bits() + 3), 0.0, 4));
+}
+
+#[test]
+fn test_macros() {
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    assert_approx_eq!(Vector2::new(1.0, 1.0), Vector2::new(1.05, 1.0), 0.1);
+    assert_relative_eq!(1.0e6f32, 1.000001e6);
+    assert_ulps_eq!(0.1f32 + 0.2, 0.3);
+
+    let m = Matrix4::from_scale(Vector3::new(3.0, 3.0, 3.0));
+    assert_approx_eq!(m * m.inverse().unwrap(), Matrix4::identity());
+}
+
+#[test]
+fn test_fixed_macros() {
+    let one = Fixed::from_i32(1);
+    let next = Fixed::from_bits(one.to_bits() + 3);
+
+    assert_ulps_eq!(one, next);
+    assert_ulps_eq!(FixedVector2::new(one, next), FixedVector2::new(next, one), 3);
+    assert!(!one.ulps_eq(&next, Fixed::default(), 2));
+}
+
+#[test]
+#[should_panic]
+fn test_fixed_ulps_failure() {
+    let one = Fixed::from_i32(1);
+    assert_ulps_eq!(one, Fixed::from_bits(one.to_bits() + 5));
+}
+
+#[test]
+#[should_panic]
+fn test_macro_failure() {
+    assert_approx_eq!(Vector2::new(1.0, 1.0), Vector2::new(1.1, 1.0));
+}
@@ -0,0 +1,133 @@
+use edocore::assert_approx_eq;
+use edocore::color::{linear_to_srgb, srgb_to_linear, Gradient, Hsva, LinearRgba, Oklab, ParseHexError, Srgba};
+use edocore::math::vector::Vector4;
+
+fn assert_color_near(a: LinearRgba, b: LinearRgba) {
+    assert_approx_eq!(Vector4::from(a), Vector4::from(b), 0.0001);
+}
+
+#[test]
+fn test_transfer_functions() {
+    assert_eq!(srgb_to_linear(0.0), 0.0);
+    assert_approx_eq!(srgb_to_linear(1.0), 1.0);
+    assert_approx_eq!(srgb_to_linear(0.5), 0.21404114);
+    assert_approx_eq!(linear_to_srgb(0.21404114), 0.5);
+    assert_approx_eq!(linear_to_srgb(0.002), 0.02584);
+
+    for i in 0..=100 {
+        let value = i as f32 / 100.0;
+        assert_approx_eq!(linear_to_srgb(srgb_to_linear(value)), value);
+    }
+}
+
+#[test]
+fn test_linear_srgb_conversion() {
+    let srgb = Srgba::new(1.0, 0.5, 0.0, 0.25);
+    let linear = LinearRgba::from(srgb);
+
+    assert_approx_eq!(linear.g, 0.21404114);
+    assert_eq!(linear.a, 0.25);
+    assert_color_near(Srgba::from(linear).into(), linear);
+}
+
+#[test]
+fn test_packing() {
+    let color = Srgba::from_rgba8(0x1020_30FF);
+
+    assert_eq!(color.to_bytes(), [0x10, 0x20, 0x30, 0xFF]);
+    assert_eq!(color.to_rgba8(), 0x1020_30FF);
+    assert_eq!(color.to_bgra8(), 0x3020_10FF);
+    assert_eq!(Srgba::from_bgra8(0x3020_10FF), color);
+    assert_eq!(Srgba::new(2.0, -1.0, 0.5, 1.0).to_bytes(), [255, 0, 128, 255]);
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(Srgba::from_hex("#FF8000").unwrap().to_bytes(), [255, 128, 0, 255]);
+    assert_eq!(Srgba::from_hex("ff800080").unwrap().to_bytes(), [255, 128, 0, 128]);
+    assert_eq!(Srgba::from_hex("#f80").unwrap().to_bytes(), [255, 136, 0, 255]);
+    assert_eq!(Srgba::from_hex("#f808").unwrap().to_bytes(), [255, 136, 0, 136]);
+    assert_eq!("#102030".parse::<Srgba>().unwrap().to_hex(), "#102030FF");
+
+    assert_eq!(Srgba::from_hex("#12345"), Err(ParseHexError::InvalidLength));
+    assert_eq!(Srgba::from_hex(""), Err(ParseHexError::InvalidLength));
+    assert_eq!(Srgba::from_hex("#12G"), Err(ParseHexError::InvalidDigit));
+    assert_eq!(Srgba::from_hex("#1é"), Err(ParseHexError::InvalidDigit));
+}
+
+#[test]
+fn test_hsv() {
+    let cases = [
+        (Srgba::rgb(1.0, 0.0, 0.0), Hsva::new(0.0, 1.0, 1.0, 1.0)),
+        (Srgba::rgb(0.0, 0.5, 0.0), Hsva::new(120.0, 1.0, 0.5, 1.0)),
+        (Srgba::rgb(0.5, 0.5, 1.0), Hsva::new(240.0, 0.5, 1.0, 1.0)),
+        (Srgba::rgb(1.0, 0.0, 0.5), Hsva::new(330.0, 1.0, 1.0, 1.0)),
+        (Srgba::rgb(0.3, 0.3, 0.3), Hsva::new(0.0, 0.0, 0.3, 1.0)),
+    ];
+
+    for &(srgb, hsv) in cases.iter() {
+        assert_eq!(Hsva::from(srgb), hsv);
+        assert_color_near(Srgba::from(hsv).into(), srgb.into());
+    }
+    assert_color_near(Srgba::from(Hsva::new(-120.0, 1.0, 1.0, 1.0)).into(), Srgba::rgb(0.0, 0.0, 1.0).into());
+}
+
+#[test]
+fn test_oklab() {
+    let white = Oklab::from(LinearRgba::WHITE);
+    assert_approx_eq!(white.l, 1.0, 0.0001);
+    assert_approx_eq!(white.a, 0.0, 0.0001);
+    assert_approx_eq!(white.b, 0.0, 0.0001);
+
+    // Reference value for sRGB red
+    let red = Oklab::from(LinearRgba::rgb(1.0, 0.0, 0.0));
+    assert_approx_eq!(red.l, 0.6279554, 0.0001);
+    assert_approx_eq!(red.a, 0.22486306, 0.0001);
+    assert_approx_eq!(red.b, 0.1258463, 0.0001);
+
+    let color = LinearRgba::new(0.2, 0.7, 0.1, 0.5);
+    assert_color_near(Oklab::from(color).into(), color);
+    assert_color_near(Oklab::lerp(red, white, 1.0).into(), LinearRgba::WHITE);
+}
+
+#[test]
+fn test_premultiplied() {
+    let color = LinearRgba::new(0.8, 0.4, 0.2, 0.5);
+
+    assert_eq!(color.premultiplied(), LinearRgba::new(0.4, 0.2, 0.1, 0.5));
+    assert_eq!(color.premultiplied().unpremultiplied(), color);
+    assert_eq!(LinearRgba::new(0.3, 0.3, 0.3, 0.0).unpremultiplied(), LinearRgba::TRANSPARENT);
+}
+
+#[test]
+fn test_blending() {
+    let red = LinearRgba::rgb(1.0, 0.0, 0.0);
+    let blue = LinearRgba::rgb(0.0, 0.0, 1.0);
+
+    assert_eq!(LinearRgba::blend_over(red, blue), red);
+    assert_eq!(LinearRgba::blend_over(LinearRgba::TRANSPARENT, blue), blue);
+    assert_color_near(LinearRgba::blend_over(LinearRgba::new(1.0, 0.0, 0.0, 0.5), blue), LinearRgba::rgb(0.5, 0.0, 0.5));
+    assert_color_near(LinearRgba::lerp(red, blue, 0.25), LinearRgba::rgb(0.75, 0.0, 0.25));
+
+    // Blending in linear space keeps the middle of red and green bright
+    let middle = Srgba::lerp(Srgba::rgb(1.0, 0.0, 0.0), Srgba::rgb(0.0, 1.0, 0.0), 0.5);
+    assert_approx_eq!(middle.r, 0.7353569, 0.0001);
+    assert_approx_eq!(middle.g, 0.7353569, 0.0001);
+}
+
+#[test]
+fn test_gradient() {
+    let gradient = Gradient::new(&[
+        (1.0, LinearRgba::WHITE),
+        (0.0, LinearRgba::BLACK),
+        (0.5, LinearRgba::rgb(1.0, 0.0, 0.0)),
+    ]);
+
+    assert_eq!(gradient.stops()[1].0, 0.5);
+    assert_eq!(gradient.evaluate(-1.0), LinearRgba::BLACK);
+    assert_eq!(gradient.evaluate(2.0), LinearRgba::WHITE);
+    assert_eq!(gradient.evaluate(0.5), LinearRgba::rgb(1.0, 0.0, 0.0));
+    assert_color_near(gradient.evaluate(0.25), LinearRgba::rgb(0.5, 0.0, 0.0));
+    assert_color_near(gradient.evaluate(0.75), LinearRgba::rgb(1.0, 0.5, 0.5));
+    assert_eq!(Gradient::default().evaluate(0.5), LinearRgba::TRANSPARENT);
+}
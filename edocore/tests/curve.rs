@@ -0,0 +1,143 @@
+use edocore::assert_approx_eq;
+use edocore::math::curve::{ArcLengthTable, BSpline, CatmullRom, CubicBezier, Curve, Hermite, QuadraticBezier};
+use edocore::math::vector::{Vector, Vector2, Vector3};
+
+/// Central difference of the curve, to check the analytic derivatives against
+fn numeric_derivative<C: Curve<f32, N>, const N: usize>(curve: &C, t: f32) -> Vector<f32, N> {
+    let h = 0.001;
+    (curve.evaluate(t + h) - curve.evaluate(t - h)) / (2.0 * h)
+}
+
+#[test]
+fn test_bezier_end_points() {
+    let quadratic = QuadraticBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0));
+    let cubic = CubicBezier::new(
+        Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(2.0, 1.0, 0.0), Vector3::new(3.0, 0.0, 0.0),
+    );
+
+    assert_eq!(quadratic.evaluate(0.0), Vector2::new(0.0, 0.0));
+    assert_eq!(quadratic.evaluate(1.0), Vector2::new(2.0, 0.0));
+    assert_approx_eq!(quadratic.evaluate(0.5), Vector2::new(1.0, 1.0));
+    assert_eq!(cubic.evaluate(0.0), Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(cubic.evaluate(1.0), Vector3::new(3.0, 0.0, 0.0));
+    assert_approx_eq!(cubic.evaluate(0.5), Vector3::new(1.5, 0.75, 0.0));
+    assert_approx_eq!(cubic.derivative(0.0), Vector3::new(3.0, 3.0, 0.0));
+}
+
+#[test]
+fn test_derivatives() {
+    let points = vec![
+        Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(3.0, 3.0),
+        Vector2::new(4.0, 1.0), Vector2::new(6.0, 0.0),
+    ];
+    let quadratic = QuadraticBezier::new(points[0], points[1], points[2]);
+    let cubic = CubicBezier::new(points[0], points[1], points[2], points[3]);
+    let hermite = Hermite::new(points[0], points[1], points[2], points[3]);
+    let catmull_rom = CatmullRom::new(points.clone());
+    let b_spline = BSpline::new(points);
+
+    for &t in [0.1, 0.3, 0.6, 0.9].iter() {
+        assert_approx_eq!(quadratic.derivative(t), numeric_derivative(&quadratic, t), 0.01);
+        assert_approx_eq!(cubic.derivative(t), numeric_derivative(&cubic, t), 0.01);
+        assert_approx_eq!(hermite.derivative(t), numeric_derivative(&hermite, t), 0.01);
+        assert_approx_eq!(catmull_rom.derivative(t), numeric_derivative(&catmull_rom, t), 0.01);
+        assert_approx_eq!(b_spline.derivative(t), numeric_derivative(&b_spline, t), 0.01);
+    }
+}
+
+#[test]
+fn test_hermite() {
+    let hermite = Hermite::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(0.0, 1.0));
+
+    assert_eq!(hermite.evaluate(0.0), Vector2::new(0.0, 0.0));
+    assert_eq!(hermite.evaluate(1.0), Vector2::new(1.0, 1.0));
+    assert_approx_eq!(hermite.derivative(0.0), Vector2::new(1.0, 0.0));
+    assert_approx_eq!(hermite.derivative(1.0), Vector2::new(0.0, 1.0));
+}
+
+#[test]
+fn test_catmull_rom_passes_through_points() {
+    let points = vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0), Vector2::new(3.0, 1.0)];
+    let spline = CatmullRom::new(points.clone());
+
+    for (i, &point) in points.iter().enumerate() {
+        assert_approx_eq!(spline.evaluate(i as f32 / 3.0), point);
+    }
+    assert_eq!(spline.evaluate(-1.0), points[0]);
+    assert_eq!(spline.evaluate(2.0), points[3]);
+    assert_eq!(spline.points(), &points[..]);
+}
+
+#[test]
+fn test_b_spline() {
+    // Equally spaced points on a line give a straight line at constant speed
+    let points = (0..6).map(|i| Vector2::new(i as f32, 0.0)).collect();
+    let spline = BSpline::new(points);
+
+    assert_approx_eq!(spline.evaluate(0.0), Vector2::new(1.0, 0.0));
+    assert_approx_eq!(spline.evaluate(0.5), Vector2::new(2.5, 0.0));
+    assert_approx_eq!(spline.evaluate(1.0), Vector2::new(4.0, 0.0));
+    assert_approx_eq!(spline.derivative(0.3), Vector2::new(3.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn test_b_spline_too_few_points() {
+    BSpline::new(vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_catmull_rom_too_few_points() {
+    CatmullRom::new(vec![Vector2::new(1.0, 2.0)]);
+}
+
+#[test]
+fn test_catmull_rom_two_points() {
+    let spline = CatmullRom::new(vec![Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0)]);
+
+    assert_approx_eq!(spline.evaluate(0.0), Vector2::new(0.0, 0.0));
+    assert_approx_eq!(spline.evaluate(0.5), Vector2::new(1.0, 0.0));
+    assert_approx_eq!(spline.evaluate(1.0), Vector2::new(2.0, 0.0));
+}
+
+#[test]
+fn test_arc_length() {
+    let line = QuadraticBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(4.0, 0.0));
+    assert_approx_eq!(line.arc_length(64), 4.0, 0.0001);
+
+    // A cubic Bezier approximating a quarter of the unit circle
+    let k = 0.552_284_8;
+    let arc = CubicBezier::new(Vector2::new(1.0, 0.0), Vector2::new(1.0, k), Vector2::new(k, 1.0), Vector2::new(0.0, 1.0));
+    assert_approx_eq!(arc.arc_length(256), std::f32::consts::FRAC_PI_2, 0.001);
+}
+
+#[test]
+fn test_arc_length_parameterization() {
+    // The control point bunches the parameter up near the end of the line
+    let line = QuadraticBezier::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(4.0, 0.0));
+    let table = ArcLengthTable::new(&line, 256);
+
+    assert_approx_eq!(table.length(), 4.0, 0.0001);
+    assert_eq!(table.parameter(-1.0), 0.0);
+    assert_eq!(table.parameter(10.0), 1.0);
+    for &distance in [0.5, 1.0, 2.0, 3.5].iter() {
+        assert_approx_eq!(line.evaluate(table.parameter(distance)).x, distance, 0.001);
+    }
+}
+
+#[test]
+fn test_closest_point() {
+    let arc = QuadraticBezier::new(Vector2::new(-1.0, 0.0), Vector2::new(0.0, 2.0), Vector2::new(1.0, 0.0));
+
+    assert_approx_eq!(arc.closest_parameter(Vector2::new(0.0, 5.0)), 0.5, 0.0001);
+    assert_approx_eq!(arc.closest_point(Vector2::new(0.0, 5.0)), Vector2::new(0.0, 1.0), 0.0001);
+    assert_approx_eq!(arc.closest_point(Vector2::new(-3.0, -1.0)), Vector2::new(-1.0, 0.0), 0.0001);
+
+    let f64_line = CubicBezier::new(
+        Vector::from([0.0f64, 0.0]), Vector::from([1.0, 0.0]),
+        Vector::from([2.0, 0.0]), Vector::from([3.0, 0.0]),
+    );
+    assert_approx_eq!(f64_line.closest_parameter(Vector::from([2.0, 1.0])), 2.0 / 3.0, 0.00001);
+}
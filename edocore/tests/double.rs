@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+use edocore::assert_approx_eq;
+use edocore::math::angle::Degrees;
+use edocore::math::matrix::{DMatrix4, Matrix4};
+use edocore::math::quaternion::{DQuaternion, Quaternion};
+use edocore::math::vector::{DVector3, IVector3, UVector3, Vector, Vector3};
+
+#[test]
+fn test_precision_far_from_origin() {
+    let far = DVector3::new(10_000_000.0, 10_000_000.0, -10_000_000.0);
+    let step = DVector3::new(0.001, 0.002, 0.003);
+
+    assert_approx_eq!(((far + step) - far).to_f32(), Vector3::new(0.001, 0.002, 0.003));
+
+    // The same step vanishes in single precision
+    let far32 = far.to_f32();
+    assert_eq!((far32 + step.to_f32()) - far32, Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_conversions() {
+    let v = Vector3::new(0.1, -2.5, 3.0);
+
+    assert_eq!(DVector3::from(v).to_f32(), v);
+    assert_eq!(DVector3::from(IVector3::new(1, -2, 3)), DVector3::new(1.0, -2.0, 3.0));
+    assert_eq!(DVector3::from(UVector3::new(u32::MAX, 0, 0)), DVector3::new(4_294_967_295.0, 0.0, 0.0));
+
+    assert_eq!(IVector3::try_from(DVector3::new(2_147_483_647.0, -1.5, 0.0)), Ok(IVector3::new(i32::MAX, -1, 0)));
+    assert!(IVector3::try_from(DVector3::new(2_147_483_648.0, 0.0, 0.0)).is_err());
+    assert_eq!(UVector3::try_from(DVector3::new(4_294_967_295.0, 0.0, 0.0)), Ok(UVector3::new(u32::MAX, 0, 0)));
+    assert!(UVector3::try_from(DVector3::new(f64::NAN, 0.0, 0.0)).is_err());
+}
+
+#[test]
+fn test_matrix() {
+    let rotation = DQuaternion::euler(Degrees(20.0), Degrees(40.0), Degrees(60.0));
+    let m = DMatrix4::trs(DVector3::new(5_000_000.0, 1.0, -3.0), rotation, DVector3::new(2.0, 2.0, 2.0));
+    let p = DVector3::new(0.25, 0.5, 0.75);
+
+    assert_approx_eq!(m.inverse().unwrap().transform_point(m.transform_point(p)), p);
+    assert_approx_eq!(m.to_f32(), Matrix4::trs(Vector3::new(5_000_000.0, 1.0, -3.0), rotation.to_f32(), Vector3::new(2.0, 2.0, 2.0)));
+    assert_eq!(DMatrix4::from(Matrix4::identity()), DMatrix4::identity());
+}
+
+#[test]
+fn test_quaternion() {
+    let q = DQuaternion::axis_angle(DVector3::new(0.0, 0.0, 1.0), Degrees(90.0));
+
+    assert_approx_eq!(q * DVector3::new(1.0, 0.0, 0.0), DVector3::new(0.0, 1.0, 0.0));
+    assert_approx_eq!(DQuaternion::euler(Degrees(10.0), Degrees(20.0), Degrees(30.0)).euler_angles(), Vector::from([Degrees(10.0), Degrees(20.0), Degrees(30.0)]));
+    assert_approx_eq!(DQuaternion::slerp(DQuaternion::identity(), q, 0.5), DQuaternion::axis_angle(DVector3::new(0.0, 0.0, 1.0), Degrees(45.0)));
+
+    let single = Quaternion::axis_angle(Vector3::new(0.0, 0.0, 1.0), Degrees(90.0));
+    assert_eq!(DQuaternion::from(single).to_f32(), single);
+    assert_approx_eq!(q.to_f32(), single);
+}
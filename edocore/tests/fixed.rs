@@ -0,0 +1,110 @@
+use edocore::assert_approx_eq;
+use edocore::math::fixed::Fixed;
+use edocore::math::matrix::{FixedMatrix3, Matrix3};
+use edocore::math::scalar::{Float, Scalar};
+use edocore::math::vector::{FixedVector2, FixedVector3, Vector, Vector2, Vector3};
+
+fn fixed(value: f32) -> Fixed {
+    Fixed::from_f32(value)
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(Fixed::from_i32(3).to_bits(), 3 << 16);
+    assert_eq!(Fixed::from(-2).to_f32(), -2.0);
+    assert_eq!(fixed(1.5).to_bits(), 0x18000);
+    assert_eq!(fixed(-0.25).to_f64(), -0.25);
+    assert_eq!(fixed(-1.5).to_i32(), -2);
+    assert_eq!(fixed(1.0e9), Fixed::MAX);
+    assert_eq!(fixed(f32::NAN), Fixed::ZERO);
+    assert_eq!(Fixed::ONE.to_string(), "1");
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(fixed(1.5) + fixed(2.25), fixed(3.75));
+    assert_eq!(fixed(1.5) - fixed(2.25), fixed(-0.75));
+    assert_eq!(fixed(1.5) * fixed(-2.25), fixed(-3.375));
+    assert_eq!(fixed(3.0) / fixed(-2.0), fixed(-1.5));
+    assert_eq!(-fixed(4.0), fixed(-4.0));
+
+    let mut value = fixed(2.0);
+    value *= fixed(3.0);
+    value -= Fixed::ONE;
+    assert_eq!(value, fixed(5.0));
+
+    // Overflow wraps instead of panicking, in debug and release alike
+    assert_eq!(Fixed::MAX + Fixed::RESOLUTION, Fixed::MIN);
+}
+
+#[test]
+#[should_panic]
+fn test_divide_by_zero() {
+    let _ = Fixed::ONE / Fixed::ZERO;
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(fixed(4.0).sqrt(), fixed(2.0));
+    assert_eq!(fixed(0.25).sqrt(), fixed(0.5));
+    assert_eq!(fixed(-1.0).sqrt(), Fixed::ZERO);
+    assert_approx_eq!(fixed(2.0).sqrt(), fixed(std::f32::consts::SQRT_2), Fixed::RESOLUTION);
+    assert_approx_eq!(Fixed::MAX.sqrt().to_f32(), 181.02, 0.01);
+}
+
+#[test]
+fn test_acos() {
+    for i in -10..=10 {
+        let x = i as f32 / 10.0;
+        assert_approx_eq!(fixed(x).acos().to_f32(), x.acos(), 0.0002);
+    }
+    assert_approx_eq!(fixed(0.5).acos().to_degrees().to_f32(), 60.0, 0.01);
+}
+
+#[test]
+fn test_vector() {
+    let a = FixedVector3::from_f32(Vector3::new(1.0, 2.0, 3.0));
+    let b = FixedVector3::from_f32(Vector3::new(-2.0, 0.5, 4.0));
+
+    assert_eq!(Vector::dot(a, b), fixed(11.0));
+    assert_eq!(Vector::cross(a, b).to_f32(), Vector3::cross(a.to_f32(), b.to_f32()));
+    assert_eq!((a + b * fixed(2.0)).to_f32(), Vector3::new(-3.0, 3.0, 11.0));
+    assert_eq!(fixed(2.0) * a, a + a);
+    assert_eq!(FixedVector2::new(fixed(3.0), fixed(4.0)).magnitude(), fixed(5.0));
+    assert_eq!(Vector::lerp(a, b, fixed(0.5)).to_f32(), Vector3::new(-0.5, 1.25, 3.5));
+    assert_approx_eq!(a.normalized().to_f32(), a.to_f32().normalized(), 0.0001);
+    assert_approx_eq!(Vector::angle(a, b).0.to_f32(), Vector3::angle(a.to_f32(), b.to_f32()).0, 0.02);
+}
+
+#[test]
+fn test_long_vectors() {
+    let a = FixedVector3::from_f32(Vector3::new(200.0, 0.0, 0.0));
+    assert_eq!(a.magnitude(), fixed(200.0));
+    assert_eq!(a.square_magnitude(), Fixed::MAX);
+    assert_eq!(a.normalized(), FixedVector3::from_f32(Vector3::new(1.0, 0.0, 0.0)));
+    assert_eq!(Vector::distance(a, -a), fixed(400.0));
+
+    let b = FixedVector2::from_f32(Vector2::new(150.0, 150.0));
+    assert_approx_eq!(b.magnitude().to_f32(), 212.132_03, 0.0001);
+    assert_approx_eq!(b.normalized().to_f32(), Vector2::new(1.0, 1.0).normalized(), 0.0001);
+    assert_eq!(Vector::dot(b, FixedVector2::from_f32(Vector2::new(150.0, -150.0))), Fixed::ZERO);
+
+    let c = FixedVector3::from_f32(Vector3::new(30000.0, 0.0, 0.0));
+    assert_eq!(c.magnitude(), fixed(30000.0));
+    assert_eq!(FixedVector2::from_f32(Vector2::new(18000.0, 24000.0)).magnitude(), fixed(30000.0));
+    assert_eq!(Vector::distance(c, FixedVector3::from_f32(Vector3::new(0.0, 0.0, 0.0))), fixed(30000.0));
+    assert_eq!(c.normalized().to_f32(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(FixedVector2::new(Fixed::MIN, Fixed::MIN).magnitude(), Fixed::MAX);
+}
+
+#[test]
+fn test_matrix() {
+    let float = Matrix3::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 1.0));
+    let matrix = FixedMatrix3::from_f32(float);
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(matrix.determinant(), Fixed::ONE);
+    assert_eq!(matrix * inverse, FixedMatrix3::identity());
+    assert_eq!(inverse.to_f32(), float.inverse().unwrap());
+    assert_eq!((matrix * FixedVector3::from_f32(Vector3::new(1.0, 2.0, 3.0))).to_f32(), Vector3::new(5.0, 2.0, 4.0));
+}
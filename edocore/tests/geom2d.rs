@@ -0,0 +1,172 @@
+use edocore::assert_approx_eq;
+use edocore::math::geom2d::{self, Segment, SegmentIntersection, Winding};
+use edocore::math::vector::Vector2;
+
+fn square(x: f32, y: f32, size: f32) -> Vec<Vector2> {
+    vec![Vector2::new(x, y), Vector2::new(x + size, y), Vector2::new(x + size, y + size), Vector2::new(x, y + size)]
+}
+
+fn total_area(outlines: &[Vec<Vector2>]) -> f32 {
+    outlines.iter().map(|outline| geom2d::signed_area(outline)).sum()
+}
+
+fn triangles_area(points: &[Vector2], triangles: &[[usize; 3]]) -> f32 {
+    triangles.iter().map(|t| geom2d::signed_area(&[points[t[0]], points[t[1]], points[t[2]]])).sum()
+}
+
+#[test]
+fn test_segment_intersection() {
+    let a = Segment::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 2.0));
+
+    assert_eq!(a.intersect(Segment::new(Vector2::new(0.0, 2.0), Vector2::new(2.0, 0.0))), Some(SegmentIntersection::Point(Vector2::new(1.0, 1.0))));
+    assert_eq!(a.intersect(Segment::new(Vector2::new(2.0, 2.0), Vector2::new(3.0, 0.0))), Some(SegmentIntersection::Point(Vector2::new(2.0, 2.0))));
+    assert_eq!(a.intersect(Segment::new(Vector2::new(0.0, 1.0), Vector2::new(2.0, 3.0))), None);
+    assert_eq!(a.intersect(Segment::new(Vector2::new(3.0, 0.0), Vector2::new(4.0, -1.0))), None);
+    assert_eq!(
+        a.intersect(Segment::new(Vector2::new(3.0, 3.0), Vector2::new(1.0, 1.0))),
+        Some(SegmentIntersection::Overlap(Segment::new(Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0))))
+    );
+}
+
+#[test]
+fn test_area_and_winding() {
+    let mut polygon = square(1.0, 1.0, 2.0);
+
+    assert_eq!(geom2d::signed_area(&polygon), 4.0);
+    assert_eq!(geom2d::winding(&polygon), Some(Winding::CounterClockwise));
+
+    polygon.reverse();
+    assert_eq!(geom2d::signed_area(&polygon), -4.0);
+    assert_eq!(geom2d::area(&polygon), 4.0);
+    assert_eq!(geom2d::winding(&polygon), Some(Winding::Clockwise));
+    assert_eq!(geom2d::winding(&[Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0)]), None);
+}
+
+#[test]
+fn test_contains_point() {
+    // A U shape open toward +y
+    let polygon = [
+        Vector2::new(0.0, 0.0), Vector2::new(3.0, 0.0), Vector2::new(3.0, 3.0), Vector2::new(2.0, 3.0),
+        Vector2::new(2.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 3.0), Vector2::new(0.0, 3.0),
+    ];
+
+    assert!(geom2d::contains_point(&polygon, Vector2::new(0.5, 2.0)));
+    assert!(geom2d::contains_point(&polygon, Vector2::new(1.5, 0.5)));
+    assert!(!geom2d::contains_point(&polygon, Vector2::new(1.5, 2.0)));
+    assert!(!geom2d::contains_point(&polygon, Vector2::new(4.0, 0.5)));
+}
+
+#[test]
+fn test_convex_hull() {
+    let points = [
+        Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0), Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0), Vector2::new(0.5, 1.5), Vector2::new(2.0, 2.0),
+    ];
+
+    assert_eq!(geom2d::convex_hull(&points), square(0.0, 0.0, 2.0));
+    assert_eq!(geom2d::convex_hull(&points[..2]).len(), 2);
+}
+
+#[test]
+fn test_triangulate() {
+    let square = square(0.0, 0.0, 2.0);
+    let triangles = geom2d::triangulate(&square, &[]);
+    assert_eq!(triangles.len(), 2);
+    assert_eq!(triangles_area(&square, &triangles), 4.0);
+
+    // A clockwise L shape
+    let mut l_shape = vec![
+        Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 1.0),
+        Vector2::new(1.0, 1.0), Vector2::new(1.0, 2.0), Vector2::new(0.0, 2.0),
+    ];
+    l_shape.reverse();
+    let triangles = geom2d::triangulate(&l_shape, &[]);
+    assert_eq!(triangles.len(), 4);
+    assert_eq!(triangles_area(&l_shape, &triangles), 3.0);
+}
+
+#[test]
+fn test_triangulate_holes() {
+    let outer = square(0.0, 0.0, 10.0);
+    let left = square(1.0, 4.0, 2.0);
+    let mut right = square(6.0, 3.0, 3.0);
+    right.reverse();
+
+    let triangles = geom2d::triangulate(&outer, &[&left, &right]);
+    let points: Vec<Vector2> = outer.iter().chain(left.iter()).chain(right.iter()).copied().collect();
+
+    assert_eq!(triangles.len(), 4 + 4 + 4 + 2 * 2 - 2);
+    assert_approx_eq!(triangles_area(&points, &triangles), 100.0 - 4.0 - 9.0);
+    for triangle in triangles {
+        assert!(geom2d::signed_area(&[points[triangle[0]], points[triangle[1]], points[triangle[2]]]) > 0.0);
+    }
+}
+
+#[test]
+fn test_triangulate_degenerate_hole() {
+    let outer = square(0.0, 0.0, 10.0);
+    let line = [Vector2::new(8.0, 8.0), Vector2::new(9.0, 9.0)];
+    let hole = square(3.0, 3.0, 2.0);
+
+    let triangles = geom2d::triangulate(&outer, &[&line, &hole]);
+    let points: Vec<Vector2> = outer.iter().chain(line.iter()).chain(hole.iter()).copied().collect();
+
+    assert_eq!(triangles.len(), 4 + 4 + 2 - 2);
+    assert_approx_eq!(triangles_area(&points, &triangles), 100.0 - 4.0);
+    for triangle in triangles {
+        assert!(triangle.iter().all(|&i| !(4..6).contains(&i)));
+    }
+}
+
+#[test]
+fn test_boolean_overlapping() {
+    let a = square(0.0, 0.0, 2.0);
+    let b = square(1.0, 1.0, 2.0);
+
+    let union = geom2d::union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].len(), 8);
+    assert_eq!(total_area(&union), 7.0);
+
+    let intersection = geom2d::intersection(&a, &b);
+    assert_eq!(intersection.len(), 1);
+    assert_eq!(total_area(&intersection), 1.0);
+
+    assert_eq!(total_area(&geom2d::difference(&a, &b)), 3.0);
+    assert_eq!(total_area(&geom2d::difference(&b, &a)), 3.0);
+}
+
+#[test]
+fn test_boolean_nested_and_disjoint() {
+    let outer = square(0.0, 0.0, 4.0);
+    let inner = square(1.0, 1.0, 2.0);
+    let far = square(10.0, 0.0, 1.0);
+
+    let hole = geom2d::difference(&outer, &inner);
+    assert_eq!(hole.len(), 2);
+    assert_eq!(total_area(&hole), 12.0);
+    assert!(hole.iter().any(|outline| geom2d::winding(outline) == Some(Winding::Clockwise)));
+
+    assert_eq!(total_area(&geom2d::union(&outer, &inner)), 16.0);
+    assert_eq!(total_area(&geom2d::intersection(&outer, &inner)), 4.0);
+    assert_eq!(geom2d::union(&outer, &far).len(), 2);
+    assert!(geom2d::intersection(&outer, &far).is_empty());
+}
+
+#[test]
+fn test_boolean_shared_edges() {
+    let a = square(0.0, 0.0, 2.0);
+    let b = square(2.0, 0.0, 2.0);
+    let inside = square(0.0, 0.0, 1.0);
+
+    let union = geom2d::union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(total_area(&union), 8.0);
+    assert!(geom2d::intersection(&a, &b).is_empty());
+    assert_eq!(total_area(&geom2d::difference(&a, &b)), 4.0);
+
+    // Sharing a corner and two half edges
+    assert_eq!(total_area(&geom2d::union(&a, &inside)), 4.0);
+    assert_eq!(total_area(&geom2d::intersection(&a, &inside)), 1.0);
+    assert_eq!(total_area(&geom2d::difference(&a, &inside)), 3.0);
+}
@@ -0,0 +1,158 @@
+use edocore::assert_approx_eq;
+use edocore::math::EPSILON;
+use edocore::math::angle::Degrees;
+use edocore::math::geometry::{Aabb, Frustum, Obb, Plane, Ray, Sphere, Triangle};
+use edocore::math::matrix::{ClipSpace, Matrix4};
+use edocore::math::quaternion::Quaternion;
+use edocore::math::random::Random;
+use edocore::math::vector::Vector3;
+
+fn unit_box() -> Aabb {
+    Aabb::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(-1.0, -1.0, -1.0))
+}
+
+#[test]
+fn test_ray_aabb() {
+    let ray = Ray::new(Vector3::new(-5.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0));
+
+    assert_eq!(ray.intersect_aabb(unit_box()), Some(4.0));
+    assert_eq!(Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)).intersect_aabb(unit_box()), Some(0.0));
+    assert_eq!(Ray::new(Vector3::new(-5.0, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0)).intersect_aabb(unit_box()), None);
+}
+
+#[test]
+fn test_ray_sphere() {
+    let sphere = Sphere::new(Vector3::new(0.0, 0.0, 10.0), 2.0);
+
+    assert_eq!(Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0)).intersect_sphere(sphere), Some(8.0));
+    assert_eq!(Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0)).intersect_sphere(sphere), None);
+}
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::from_point_normal(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let ray = Ray::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+    assert_eq!(ray.intersect_plane(plane), Some(3.0));
+    assert_eq!(plane.signed_distance(Vector3::new(5.0, 1.0, 5.0)), -2.0);
+    assert_approx_eq!(plane.closest_point(Vector3::new(2.0, 7.0, 1.0)), Vector3::new(2.0, 3.0, 1.0));
+}
+
+#[test]
+fn test_ray_triangle() {
+    let triangle = Triangle::new(Vector3::new(0.0, 0.0, 5.0), Vector3::new(2.0, 0.0, 5.0), Vector3::new(0.0, 2.0, 5.0));
+    let hit = Ray::new(Vector3::new(0.5, 0.5, 0.0), Vector3::new(0.0, 0.0, 1.0)).intersect_triangle(triangle);
+
+    assert_approx_eq!(hit.unwrap(), 5.0);
+    assert_eq!(Ray::new(Vector3::new(3.0, 3.0, 0.0), Vector3::new(0.0, 0.0, 1.0)).intersect_triangle(triangle), None);
+    assert_approx_eq!(triangle.normal(), Vector3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_closest_points() {
+    let triangle = Triangle::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+
+    assert_approx_eq!(triangle.closest_point(Vector3::new(0.5, 0.5, 3.0)), Vector3::new(0.5, 0.5, 0.0));
+    assert_approx_eq!(triangle.closest_point(Vector3::new(3.0, -1.0, 0.0)), Vector3::new(2.0, 0.0, 0.0));
+    assert_approx_eq!(triangle.closest_point(Vector3::new(2.0, 2.0, 0.0)), Vector3::new(1.0, 1.0, 0.0));
+    assert_approx_eq!(triangle.barycentric(Vector3::new(1.0, 1.0, 0.0)), Vector3::new(0.0, 0.5, 0.5));
+
+    assert_eq!(unit_box().closest_point(Vector3::new(3.0, 0.5, -4.0)), Vector3::new(1.0, 0.5, -1.0));
+    assert_approx_eq!(Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.0).closest_point(Vector3::new(0.0, 5.0, 0.0)), Vector3::new(0.0, 2.0, 0.0));
+    assert_approx_eq!(Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0)).closest_point(Vector3::new(3.0, 4.0, 0.0)), Vector3::new(3.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_overlap() {
+    let sphere = Sphere::new(Vector3::new(2.5, 0.0, 0.0), 1.0);
+
+    assert!(!unit_box().intersects_sphere(sphere));
+    assert!(unit_box().intersects_sphere(Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0)));
+    assert!(unit_box().intersects_aabb(Aabb::from_center_extents(Vector3::new(1.5, 1.5, 1.5), Vector3::new(1.0, 1.0, 1.0))));
+    assert!(!unit_box().intersects_aabb(Aabb::from_center_extents(Vector3::new(3.5, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0))));
+    assert!(sphere.intersects_sphere(Sphere::new(Vector3::new(4.0, 0.0, 0.0), 0.5)));
+    assert!(unit_box().contains_point(Vector3::new(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn test_frustum() {
+    let view = Matrix4::look_at(Vector3::new(0.0, 0.0, -10.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+    for clip in [ClipSpace::OpenGl, ClipSpace::Vulkan].iter() {
+        let frustum = Frustum::from_matrix(Matrix4::perspective(Degrees(90.0), 1.0, 1.0, 100.0, *clip) * view, *clip);
+
+        assert!(frustum.contains_point(Vector3::new(0.0, 0.0, 0.0)));
+        assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, -10.5)));
+        assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, 95.0)));
+        assert!(frustum.intersects_sphere(Sphere::new(Vector3::new(12.0, 0.0, 0.0), 2.0)));
+        assert!(!frustum.intersects_sphere(Sphere::new(Vector3::new(0.0, 14.0, 0.0), 2.0)));
+        assert!(frustum.intersects_aabb(unit_box()));
+        assert!(!frustum.intersects_aabb(Aabb::from_center_extents(Vector3::new(-20.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0))));
+    }
+}
+
+#[test]
+fn test_aabb_from_points() {
+    let points = [Vector3::new(1.0, -2.0, 0.0), Vector3::new(-1.0, 4.0, 2.0), Vector3::new(0.0, 0.0, -3.0)];
+    let aabb = Aabb::from_points(&points).unwrap();
+
+    assert_eq!(aabb, Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 4.0, 2.0)));
+    assert_eq!(Aabb::from_points(&[]), None);
+    assert_eq!(unit_box().encapsulate(Vector3::new(0.0, 3.0, 0.0)).max, Vector3::new(1.0, 3.0, 1.0));
+    assert_eq!(unit_box().merge(aabb), Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 4.0, 2.0)));
+}
+
+#[test]
+fn test_sphere_from_points() {
+    let square = [Vector3::new(1.0, 1.0, 0.0), Vector3::new(-1.0, 1.0, 0.0), Vector3::new(-1.0, -1.0, 0.0), Vector3::new(1.0, -1.0, 0.0)];
+    let sphere = Sphere::from_points(&square).unwrap();
+    assert_approx_eq!(sphere.center, Vector3::new(0.0, 0.0, 0.0));
+    assert_approx_eq!(sphere.radius, 2.0f32.sqrt());
+
+    let line = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0)];
+    assert_eq!(Sphere::from_points(&line), Some(Sphere::new(Vector3::new(2.0, 0.0, 0.0), 2.0)));
+    assert_eq!(Sphere::from_points(&[]), None);
+
+    // Points on and inside a sphere of radius 3
+    let mut random = Random::new(7);
+    let center = Vector3::new(5.0, -2.0, 1.0);
+    let points: Vec<Vector3> = (0..500)
+        .map(|i| center + if i % 2 == 0 { random.on_unit_sphere() * 3.0 } else { random.inside_unit_sphere() * 3.0 })
+        .collect();
+    let sphere = Sphere::from_points(&points).unwrap();
+
+    assert!(sphere.radius <= 3.0 + EPSILON && sphere.radius > 2.95);
+    assert!(points.iter().all(|&p| Vector3::distance(p, sphere.center) <= sphere.radius + EPSILON));
+}
+
+#[test]
+fn test_sphere_merge() {
+    let a = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0);
+
+    assert_eq!(a.merge(b), Sphere::new(Vector3::new(2.0, 0.0, 0.0), 3.0));
+    assert_eq!(a.merge(Sphere::new(Vector3::new(0.5, 0.0, 0.0), 0.25)), a);
+    assert_eq!(a.encapsulate(Vector3::new(0.0, 3.0, 0.0)), Sphere::new(Vector3::new(0.0, 1.0, 0.0), 2.0));
+}
+
+#[test]
+fn test_obb_from_points() {
+    let rotation = Quaternion::euler(Degrees(30.0), Degrees(20.0), Degrees(-50.0));
+    let expected = Obb::new(Vector3::new(10.0, 0.0, -5.0), Vector3::new(4.0, 2.0, 1.0), rotation);
+    let mut points = expected.corners().to_vec();
+    points.push(expected.center);
+    let obb = Obb::from_points(&points).unwrap();
+
+    assert_approx_eq!(obb.center, expected.center, 1e-4);
+    assert_approx_eq!(obb.extents, expected.extents, 1e-4);
+    for (axis, expected_axis) in obb.axes().iter().zip(expected.axes().iter()) {
+        assert!(Vector3::dot(*axis, *expected_axis).abs() > 0.9999);
+    }
+    assert!(points.iter().all(|&p| obb.contains_point(p)));
+    assert!(!obb.contains_point(expected.center + expected.axes()[2] * 1.5));
+    assert_eq!(Obb::from_points(&[]), None);
+
+    let other = Obb::new(Vector3::new(-3.0, 1.0, 2.0), Vector3::new(1.0, 1.0, 1.0), Quaternion::identity());
+    let merged = obb.merge(other);
+    assert!(obb.corners().iter().chain(other.corners().iter()).all(|&p| merged.contains_point(p)));
+}
@@ -0,0 +1,109 @@
+use edocore::assert_approx_eq;
+use edocore::math::fixed::Fixed;
+use edocore::math::integrate::{self, State};
+use edocore::math::vector::{FixedVector2, Vector2, Vector3};
+
+const GRAVITY: Vector3 = Vector3::new(0.0, -10.0, 0.0);
+
+fn spring(position: Vector2) -> Vector2 {
+    -position * 4.0
+}
+
+fn spring_energy(state: State<f32, 2>) -> f32 {
+    0.5 * state.velocity.square_magnitude() + 2.0 * state.position.square_magnitude()
+}
+
+#[test]
+fn test_semi_implicit_euler() {
+    let state = State::new(Vector3::new(0.0, 10.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    let next = integrate::semi_implicit_euler(state, 0.5, |_, _| GRAVITY);
+
+    assert_eq!(next.velocity, Vector3::new(1.0, -5.0, 0.0));
+    assert_eq!(next.position, Vector3::new(0.5, 7.5, 0.0));
+
+    // The energy of a spring stays bounded instead of growing every step
+    let mut state = State::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+    let start = spring_energy(state);
+    for _ in 0..10_000 {
+        state = integrate::semi_implicit_euler(state, 0.01, |position, _| spring(position));
+    }
+    assert!((spring_energy(state) - start).abs() < 0.05 * start);
+}
+
+#[test]
+fn test_velocity_verlet() {
+    // Exact for a constant acceleration
+    let mut state = State::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 5.0, 0.0));
+    for _ in 0..10 {
+        state = integrate::velocity_verlet(state, 0.1, |_| GRAVITY);
+    }
+    assert_approx_eq!(state.position, Vector3::new(2.0, 0.0, 0.0));
+    assert_approx_eq!(state.velocity, Vector3::new(2.0, -5.0, 0.0));
+
+    let mut state = State::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 0.0));
+    for _ in 0..1000 {
+        state = integrate::velocity_verlet(state, 0.01, spring);
+    }
+    // x(t) = cos(2t)
+    assert_approx_eq!(state.position.x, 20.0f32.cos(), 1e-2);
+}
+
+#[test]
+fn test_rk4() {
+    let mut y = 1.0f32;
+    let mut t = 0.0;
+    for _ in 0..10 {
+        y = integrate::rk4(y, t, 0.1, |_, y| -y);
+        t += 0.1;
+    }
+    assert_approx_eq!(y, (-1.0f32).exp(), 1e-6);
+
+    // Time dependent: dy/dt = 2t
+    assert_approx_eq!(integrate::rk4(Vector2::new(0.0, 1.0), 0.0, 2.0, |t, _| Vector2::new(2.0 * t, 0.0)), Vector2::new(4.0, 1.0));
+
+    let mut state = State::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 2.0));
+    for step in 0..100 {
+        state = integrate::rk4(state, step as f32 * 0.01, 0.01, |_, s: State<f32, 2>| State::new(s.velocity, spring(s.position)));
+    }
+    assert_approx_eq!(state.position, Vector2::new(2.0f32.cos(), 2.0f32.sin()), 1e-5);
+}
+
+#[test]
+fn test_smooth_damp() {
+    let target = Vector2::new(10.0, -4.0);
+    let mut current = Vector2::new(0.0, 0.0);
+    let mut velocity = Vector2::new(0.0, 0.0);
+    let mut previous = current;
+
+    for _ in 0..200 {
+        current = integrate::smooth_damp(current, target, &mut velocity, 0.3, 1.0 / 60.0);
+        // Never passes the target
+        assert!(current.x <= target.x && current.y >= target.y);
+        assert!(current.x >= previous.x);
+        previous = current;
+    }
+    assert_approx_eq!(current, target, 1e-3);
+
+    // Moving fast enough to pass the target stops on it
+    let mut velocity = Vector2::new(1000.0, -400.0);
+    assert_eq!(integrate::smooth_damp(Vector2::new(0.0, 0.0), target, &mut velocity, 0.3, 0.1), target);
+    assert_eq!(velocity, Vector2::new(0.0, 0.0));
+}
+
+#[test]
+fn test_deterministic_fixed() {
+    let run = || {
+        let target = FixedVector2::new(Fixed::from_i32(5), Fixed::from_i32(-3));
+        let mut current = FixedVector2::new(Fixed::from_i32(0), Fixed::from_i32(0));
+        let mut velocity = current;
+        let dt = Fixed::from_f64(1.0 / 30.0);
+        for _ in 0..60 {
+            current = integrate::smooth_damp(current, target, &mut velocity, Fixed::from_f64(0.5), dt);
+        }
+        let state = integrate::velocity_verlet(State::new(current, velocity), dt, |p| -p);
+        (current, state)
+    };
+
+    assert_eq!(run(), run());
+    assert_approx_eq!(run().0.to_f32(), Vector2::new(5.0, -3.0), 0.05);
+}
@@ -0,0 +1,145 @@
+use edocore::color::LinearRgba;
+use edocore::impl_uniform_layout;
+use edocore::math::layout::{as_bytes, cast_slice, BufferLayout, UniformLayout};
+use edocore::math::matrix::{Matrix3, Matrix4};
+use edocore::math::quaternion::Quaternion;
+use edocore::math::vector::{Vector2, Vector3, Vector4};
+
+struct Camera {
+    view_projection: Matrix4,
+    position: Vector3,
+    exposure: f32,
+}
+
+struct Lights {
+    count: u32,
+    directions: [Vector3; 2],
+    ambient: LinearRgba,
+    normal: Matrix3,
+    scale: Vector2,
+}
+
+impl_uniform_layout!(Camera { view_projection: Matrix4, position: Vector3, exposure: f32 });
+impl_uniform_layout!(Lights { count: u32, directions: [Vector3; 2], ambient: LinearRgba, normal: Matrix3, scale: Vector2 });
+
+/// The macro has to expand where `Vec` is not the standard one, as in no_std crates
+mod shadowed {
+    use edocore::impl_uniform_layout;
+
+    #[allow(dead_code)]
+    struct Vec;
+
+    pub struct Fog {
+        pub density: f32,
+    }
+
+    impl_uniform_layout!(Fog { density: f32 });
+}
+
+fn floats(bytes: &[u8]) -> Vec<f32> {
+    bytes.chunks(4).map(|c| f32::from_ne_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(std::mem::size_of::<Vector3>(), 12);
+    assert_eq!(std::mem::size_of::<Matrix4>(), 64);
+    assert_eq!(std::mem::size_of::<Quaternion>(), 16);
+    assert_eq!(std::mem::size_of::<LinearRgba>(), 16);
+    assert_eq!(std::mem::align_of::<Matrix3>(), 4);
+}
+
+#[test]
+fn test_shadowed_vec() {
+    let fog = shadowed::Fog { density: 0.5 };
+    assert_eq!(fog.to_buffer(BufferLayout::Std430), 0.5f32.to_ne_bytes());
+}
+
+#[test]
+fn test_as_bytes() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(as_bytes(&vector).len(), 12);
+    assert_eq!(floats(as_bytes(&vector)), vec![1.0, 2.0, 3.0]);
+    assert_eq!(as_bytes(&0x0102_0304u32), &0x0102_0304u32.to_ne_bytes());
+}
+
+#[test]
+fn test_cast_slice() {
+    let vertices = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+
+    assert_eq!(cast_slice::<_, f32>(&vertices), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(cast_slice::<_, u8>(&vertices).len(), 24);
+    assert_eq!(cast_slice::<f32, Vector2>(&[1.0, 2.0, 3.0, 4.0]), &[Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_cast_slice_wrong_size() {
+    cast_slice::<f32, Vector2>(&[1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_basic_layouts() {
+    for &layout in [BufferLayout::Std140, BufferLayout::Std430].iter() {
+        assert_eq!((f32::alignment(layout), f32::size(layout)), (4, 4));
+        assert_eq!((Vector2::alignment(layout), Vector2::size(layout)), (8, 8));
+        assert_eq!((Vector3::alignment(layout), Vector3::size(layout)), (16, 12));
+        assert_eq!((Vector4::alignment(layout), Vector4::size(layout)), (16, 16));
+        assert_eq!((Matrix3::alignment(layout), Matrix3::size(layout)), (16, 48));
+        assert_eq!((Matrix4::alignment(layout), Matrix4::size(layout)), (16, 64));
+        assert_eq!(<[Vector3; 3]>::size(layout), 48);
+    }
+
+    // Only std140 rounds arrays and small matrices up to 16 bytes
+    assert_eq!(<[f32; 4]>::size(BufferLayout::Std140), 64);
+    assert_eq!(<[f32; 4]>::size(BufferLayout::Std430), 16);
+    assert_eq!(edocore::math::matrix::Matrix2::size(BufferLayout::Std140), 32);
+    assert_eq!(edocore::math::matrix::Matrix2::size(BufferLayout::Std430), 16);
+}
+
+#[test]
+fn test_matrix_columns() {
+    let matrix = Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+
+    assert_eq!(
+        floats(&matrix.to_buffer(BufferLayout::Std430)),
+        vec![1.0, 4.0, 7.0, 0.0, 2.0, 5.0, 8.0, 0.0, 3.0, 6.0, 9.0, 0.0],
+    );
+}
+
+#[test]
+fn test_struct_layout() {
+    let camera = Camera { view_projection: Matrix4::identity(), position: Vector3::new(1.0, 2.0, 3.0), exposure: 0.5 };
+    let buffer = camera.to_buffer(BufferLayout::Std140);
+
+    // The float fills the gap after the vec3
+    assert_eq!(Camera::size(BufferLayout::Std140), 80);
+    assert_eq!(buffer.len(), 80);
+    assert_eq!(floats(&buffer[64..]), vec![1.0, 2.0, 3.0, 0.5]);
+}
+
+#[test]
+fn test_struct_padding() {
+    let lights = Lights {
+        count: 2,
+        directions: [Vector3::new(0.0, -1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)],
+        ambient: LinearRgba::rgb(0.1, 0.2, 0.3),
+        normal: Matrix3::identity(),
+        scale: Vector2::new(2.0, 3.0),
+    };
+
+    // count 0, directions 16 and 32, ambient 48, normal 64, scale 112, rounded up to 16
+    let std140 = lights.to_buffer(BufferLayout::Std140);
+    assert_eq!(Lights::size(BufferLayout::Std140), 128);
+    assert_eq!(std140.len(), 128);
+    assert_eq!(&std140[0..4], &2u32.to_ne_bytes());
+    assert_eq!(floats(&std140[16..28]), vec![0.0, -1.0, 0.0]);
+    assert_eq!(floats(&std140[32..44]), vec![1.0, 0.0, 0.0]);
+    assert_eq!(floats(&std140[48..64]), vec![0.1, 0.2, 0.3, 1.0]);
+    assert_eq!(floats(&std140[112..120]), vec![2.0, 3.0]);
+
+    // Same offsets in std430 since every member is already 16 byte aligned
+    assert_eq!(Lights::size(BufferLayout::Std430), 128);
+    assert_eq!(lights.to_buffer(BufferLayout::Std430), std140);
+}
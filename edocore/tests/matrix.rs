@@ -0,0 +1,154 @@
+use edocore::assert_approx_eq;
+use edocore::math::angle::Degrees;
+use edocore::math::matrix::{ClipSpace, DMatrix4, DecomposeError, Matrix2, Matrix3, Matrix4};
+use edocore::math::quaternion::{DQuaternion, Quaternion};
+use edocore::math::vector::{DVector3, Vector2, Vector3, Vector4};
+
+fn sample() -> Matrix4 {
+    Matrix4::trs(Vector3::new(1.0, -2.0, 3.0), Quaternion::euler(Degrees(20.0), Degrees(40.0), Degrees(60.0)), Vector3::new(2.0, 0.5, 1.5))
+}
+
+#[test]
+fn test_identity() {
+    let m = sample();
+
+    assert!(m * Matrix4::identity() == m);
+    assert_eq!(Matrix4::identity().determinant(), 1.0);
+}
+
+#[test]
+fn test_transpose() {
+    let m = sample();
+
+    assert_eq!(m.transpose().get_row(1), m.get_column(1));
+    assert!(m.transpose().transpose() == m);
+}
+
+#[test]
+fn test_determinant() {
+    let m = Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+
+    assert_eq!(m.determinant(), 24.0);
+    assert_approx_eq!(sample().determinant(), 1.5);
+}
+
+#[test]
+fn test_inverse() {
+    let m = sample();
+
+    assert_approx_eq!(m * m.inverse().unwrap(), Matrix4::identity());
+    assert!(Matrix4::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse().is_none());
+}
+
+#[test]
+fn test_transform() {
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_scale(Vector3::new(2.0, 2.0, 2.0));
+
+    assert_approx_eq!(m.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 4.0, 5.0));
+    assert_approx_eq!(m.transform_direction(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(m * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+}
+
+#[test]
+fn test_rotation() {
+    let m = Matrix4::from_rotation(Quaternion::axis_angle(Vector3::new(0.0, 1.0, 0.0), Degrees(90.0)));
+
+    assert_approx_eq!(m.transform_direction(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_look_at() {
+    let eye = Vector3::new(0.0, 0.0, -5.0);
+    let view = Matrix4::look_at(eye, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+    assert_approx_eq!(view.transform_point(eye), Vector3::new(0.0, 0.0, 0.0));
+    assert_approx_eq!(view.transform_point(Vector3::new(1.0, 2.0, 0.0)), Vector3::new(1.0, 2.0, 5.0));
+}
+
+#[test]
+fn test_perspective() {
+    let vulkan = Matrix4::perspective(Degrees(90.0), 1.0, 1.0, 10.0, ClipSpace::Vulkan);
+    let opengl = Matrix4::perspective(Degrees(90.0), 1.0, 1.0, 10.0, ClipSpace::OpenGl);
+
+    assert_approx_eq!(vulkan.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(1.0, -1.0, 0.0));
+    assert_approx_eq!(vulkan.transform_point(Vector3::new(0.0, 0.0, 10.0)), Vector3::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(opengl.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(1.0, 1.0, -1.0));
+    assert_approx_eq!(opengl.transform_point(Vector3::new(0.0, 0.0, 10.0)), Vector3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_orthographic() {
+    let vulkan = Matrix4::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0, ClipSpace::Vulkan);
+    let opengl = Matrix4::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0, ClipSpace::OpenGl);
+
+    assert_approx_eq!(vulkan.transform_point(Vector3::new(2.0, 1.0, 5.0)), Vector3::new(1.0, -1.0, 0.5));
+    assert_approx_eq!(opengl.transform_point(Vector3::new(-2.0, 1.0, 0.0)), Vector3::new(-1.0, 1.0, -1.0));
+}
+
+#[test]
+fn test_matrix2() {
+    let m = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+
+    assert_approx_eq!(m.determinant(), -2.0);
+    assert_eq!(m.get_column(1), Vector2::new(2.0, 4.0));
+    assert_eq!(m * Vector2::new(1.0, 1.0), Vector2::new(3.0, 7.0));
+
+    assert_approx_eq!(m * m.inverse().unwrap(), Matrix2::identity());
+}
+
+#[test]
+fn test_matrix3_2d_transform() {
+    let m = Matrix3::from_translation(Vector2::new(5.0, 0.0)) * Matrix3::from_angle(Degrees(90.0)) * Matrix3::from_scale(Vector2::new(2.0, 2.0));
+    let p = m.transform_point(Vector2::new(1.0, 0.0));
+
+    assert_approx_eq!(p, Vector2::new(5.0, 2.0));
+    assert_approx_eq!(m.transform_direction(Vector2::new(0.0, 1.0)), Vector2::new(-2.0, 0.0));
+}
+
+#[test]
+fn test_conversions() {
+    let m = sample();
+    let upper: Matrix3 = m.into();
+
+    assert_eq!(upper.get_row(2), Vector3::new(m.values[2][0], m.values[2][1], m.values[2][2]));
+    assert_eq!(Matrix4::from(upper).get_column(3), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(Matrix2::from(m).values, [[m.values[0][0], m.values[0][1]], [m.values[1][0], m.values[1][1]]]);
+    assert!(Matrix3::from(Matrix2::identity()) == Matrix3::identity());
+}
+
+#[test]
+fn test_normal_matrix() {
+    let m = Matrix4::trs(Vector3::new(3.0, 0.0, 0.0), Quaternion::identity(), Vector3::new(2.0, 1.0, 1.0));
+    let normal = m.normal_matrix().unwrap() * Vector3::new(1.0, 1.0, 0.0);
+
+    assert_approx_eq!(normal, Vector3::new(0.5, 1.0, 0.0));
+}
+
+#[test]
+fn test_decompose() {
+    let rotation = Quaternion::euler(Degrees(20.0), Degrees(40.0), Degrees(60.0));
+    let parts = sample().decompose().unwrap();
+
+    assert_approx_eq!(parts.translation, Vector3::new(1.0, -2.0, 3.0));
+    assert_approx_eq!(parts.scale, Vector3::new(2.0, 0.5, 1.5));
+    assert!(Quaternion::dot(parts.rotation, rotation).abs() > 1.0 - 1e-6);
+    assert_approx_eq!(parts.recompose(), sample());
+
+    let m = DMatrix4::trs(DVector3::new(1e6, 0.0, -1e6), DQuaternion::euler(Degrees(-70.0), Degrees(10.0), Degrees(130.0)), DVector3::new(0.01, 3.0, 100.0));
+    assert_approx_eq!(m.decompose().unwrap().recompose(), m);
+}
+
+#[test]
+fn test_decompose_errors() {
+    let mut shear = Matrix4::identity();
+    shear.values[0][1] = 0.5;
+    let mirror = Matrix4::from_scale(Vector3::new(1.0, -1.0, 1.0));
+    let flat = Matrix4::from_scale(Vector3::new(1.0, 0.0, 1.0));
+    let projection = Matrix4::perspective(Degrees(60.0), 1.0, 0.1, 100.0, ClipSpace::Vulkan);
+
+    assert_eq!(shear.decompose(), Err(DecomposeError::Shear));
+    assert_eq!(mirror.decompose(), Err(DecomposeError::Reflection));
+    assert_eq!(flat.decompose(), Err(DecomposeError::Singular));
+    assert_eq!(projection.decompose(), Err(DecomposeError::Projective));
+    assert_eq!((sample() * mirror).decompose(), Err(DecomposeError::Reflection));
+}
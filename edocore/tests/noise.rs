@@ -0,0 +1,144 @@
+use edocore::assert_approx_eq;
+use edocore::math::noise::{fill_region, CellValue, DomainWarp, Fractal, FractalKind, Noise, OpenSimplex, Perlin, Worley};
+use edocore::math::vector::{IVector3, Vector, Vector2, Vector3, Vector4};
+
+/// Samples a noise at pseudo random points in [-10, 10]^N
+fn samples<S: Noise<N>, const N: usize>(noise: &S, count: usize) -> Vec<f32> {
+    let mut state = 1u32;
+    (0..count).map(|_| {
+        let mut point = [0.0; N];
+        for value in point.iter_mut() {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            *value = (state >> 8) as f32 / (1 << 24) as f32 * 20.0 - 10.0;
+        }
+        noise.get(Vector::from(point))
+    }).collect()
+}
+
+#[test]
+fn test_deterministic() {
+    // Only exactly rounded operations are used, so these hold on every platform
+    let point = Vector3::new(1.3, -2.7, 0.45);
+    assert_eq!(Perlin::new(42).get(point), -0.09126391);
+    assert_eq!(OpenSimplex::new(42).get(point), -0.09804092);
+    assert_eq!(Worley::new(42).get(point), 0.4034614);
+    assert_eq!(Perlin::new(42).get(Vector2::new(0.3, 7.9)), -0.04743436);
+    assert_eq!(OpenSimplex::new(42).get(Vector2::new(0.3, 7.9)), 0.3524931);
+    assert_eq!(OpenSimplex::new(42).get(Vector4::new(0.3, 7.9, -1.2, 3.3)), -0.01030472);
+    assert_eq!(Fractal::fbm(OpenSimplex::new(7), 5).get(point), -0.26796395);
+}
+
+#[test]
+fn test_seeds() {
+    let point = Vector3::new(1.3, -2.7, 0.45);
+    assert_eq!(Perlin::new(1).get(point), Perlin::new(1).get(point));
+    assert_ne!(Perlin::new(1).get(point), Perlin::new(2).get(point));
+    assert_ne!(OpenSimplex::new(1).get(point), OpenSimplex::new(2).get(point));
+    assert_ne!(Worley::new(1).get(point), Worley::new(2).get(point));
+}
+
+#[test]
+fn test_perlin() {
+    // Gradient noise is zero at every lattice point
+    assert_eq!(Perlin::new(3).get(Vector3::new(4.0, -2.0, 7.0)), 0.0);
+    assert!(samples::<_, 2>(&Perlin::new(3), 10_000).iter().all(|v| (-1.0..=1.0).contains(v)));
+    assert!(samples::<_, 3>(&Perlin::new(3), 10_000).iter().all(|v| (-1.0..=1.0).contains(v)));
+    assert!(samples::<_, 4>(&Perlin::new(3), 10_000).iter().all(|v| (-1.0..=1.0).contains(v)));
+}
+
+#[test]
+fn test_open_simplex() {
+    for values in [
+        samples::<_, 2>(&OpenSimplex::new(5), 10_000),
+        samples::<_, 3>(&OpenSimplex::new(5), 10_000),
+        samples::<_, 4>(&OpenSimplex::new(5), 10_000),
+    ].iter() {
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+        assert!(values.iter().any(|&v| v > 0.5) && values.iter().any(|&v| v < -0.5));
+    }
+
+    // The 3D case is covered by test_continuous
+    let noise = OpenSimplex::new(5);
+    for i in 0..1000 {
+        let point = Vector4::new(i as f32 * 0.137, i as f32 * -0.071, i as f32 * 0.029, i as f32 * 0.053);
+        assert_approx_eq!(noise.get(point.xy()), noise.get(point.xy() + Vector2::new(0.0005, 0.0005)), 0.01);
+        assert_approx_eq!(noise.get(point), noise.get(point + Vector4::new(0.0005, 0.0005, 0.0005, 0.0005)), 0.01);
+    }
+}
+
+#[test]
+fn test_continuous() {
+    let perlin = Perlin::new(9);
+    let open_simplex = OpenSimplex::new(9);
+    let worley = Worley::new(9);
+    let step = Vector3::new(0.0005, 0.0005, 0.0005);
+
+    for i in 0..1000 {
+        let point = Vector3::new(i as f32 * 0.137, i as f32 * -0.071, i as f32 * 0.029);
+        assert_approx_eq!(perlin.get(point), perlin.get(point + step), 0.01);
+        assert_approx_eq!(open_simplex.get(point), open_simplex.get(point + step), 0.01);
+        assert_approx_eq!(worley.get(point), worley.get(point + step), 0.01);
+    }
+}
+
+#[test]
+fn test_worley() {
+    let nearest = Worley::new(11);
+    let second = Worley { value: CellValue::SecondNearest, ..nearest };
+    let difference = Worley { value: CellValue::Difference, ..nearest };
+
+    for i in 0..100 {
+        let point = Vector2::new(i as f32 * 0.31, i as f32 * 0.17);
+        let (a, b) = (nearest.get(point), second.get(point));
+        assert!(a >= 0.0 && a <= b);
+        assert_eq!(difference.get(point), b - a);
+    }
+}
+
+#[test]
+fn test_fractal() {
+    let fbm = Fractal::fbm(Perlin::new(2), 6);
+    let ridged = Fractal::ridged(Perlin::new(2), 6);
+    let billow = Fractal::billow(Perlin::new(2), 6);
+    let point = Vector3::new(0.7, 1.9, -3.1);
+
+    for values in [samples::<_, 3>(&fbm, 2_000), samples::<_, 3>(&ridged, 2_000), samples::<_, 3>(&billow, 2_000)].iter() {
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+    }
+
+    // One octave of fBm is the source itself
+    assert_eq!(Fractal::fbm(Perlin::new(2), 1).get(point), Perlin::new(2).get(point));
+    assert_eq!(Fractal::new(Perlin::new(2), FractalKind::Billow, 1).get(point), Perlin::new(2).get(point).abs() * 2.0 - 1.0);
+    assert_eq!(Fractal::fbm(Perlin::new(2), 0).get(point), 0.0);
+}
+
+#[test]
+fn test_domain_warp() {
+    let point = Vector3::new(0.7, 1.9, -3.1);
+    let unwarped = DomainWarp::new(OpenSimplex::new(1), OpenSimplex::new(2), 0.0);
+    let warped = DomainWarp::new(OpenSimplex::new(1), OpenSimplex::new(2), 4.0);
+
+    assert_eq!(unwarped.get(point), OpenSimplex::new(1).get(point));
+    assert_ne!(warped.get(point), OpenSimplex::new(1).get(point));
+}
+
+#[test]
+fn test_fill_region() {
+    let noise = Fractal::fbm(OpenSimplex::new(3), 3);
+    let min = IVector3::new(-2, 5, 1);
+    let max = IVector3::new(2, 8, 3);
+    let mut output = vec![0.0; 4 * 3 * 2];
+    fill_region(&noise, min, max, 0.1, &mut output);
+
+    // x changes fastest, then y, then z
+    assert_eq!(output[0], noise.get(Vector3::new(-2.0, 5.0, 1.0) * 0.1));
+    assert_eq!(output[1], noise.get(Vector3::new(-1.0, 5.0, 1.0) * 0.1));
+    assert_eq!(output[4], noise.get(Vector3::new(-2.0, 6.0, 1.0) * 0.1));
+    assert_eq!(output[23], noise.get(Vector3::new(1.0, 7.0, 2.0) * 0.1));
+}
+
+#[test]
+#[should_panic]
+fn test_fill_region_wrong_size() {
+    fill_region(&Perlin::new(0), IVector3::new(0, 0, 0), IVector3::new(2, 2, 2), 1.0, &mut [0.0; 4]);
+}
@@ -0,0 +1,72 @@
+use edocore::{assert_approx_eq, assert_relative_eq};
+use edocore::math::angle::Degrees;
+use edocore::math::matrix::Matrix4;
+use edocore::math::quaternion::Quaternion;
+use edocore::math::vector::{Vector, Vector3};
+
+fn assert_rotation_near(a: Quaternion, b: Quaternion) {
+    assert!(Quaternion::dot(a, b).abs() > 0.9999, "{} != {}", a, b);
+}
+
+#[test]
+fn test_axis_angle() {
+    let q = Quaternion::axis_angle(Vector3::new(0.0, 0.0, 2.0), Degrees(90.0));
+
+    assert_approx_eq!(q * Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+    let (axis, angle) = q.to_axis_angle();
+    assert_approx_eq!(axis, Vector3::new(0.0, 0.0, 1.0));
+    assert_relative_eq!(angle, Degrees(90.0));
+}
+
+#[test]
+fn test_compose() {
+    let a = Quaternion::axis_angle(Vector3::new(0.0, 1.0, 0.0), Degrees(90.0));
+    let b = Quaternion::axis_angle(Vector3::new(1.0, 0.0, 0.0), Degrees(90.0));
+    let v = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_approx_eq!((a * b) * v, a * (b * v));
+}
+
+#[test]
+fn test_euler() {
+    let q = Quaternion::euler(Degrees(30.0), Degrees(45.0), Degrees(60.0));
+    let angles = q.euler_angles();
+
+    assert_approx_eq!(angles, Vector::from([Degrees(30.0), Degrees(45.0), Degrees(60.0)]));
+    assert_rotation_near(Quaternion::euler(angles.x, angles.y, angles.z), q);
+}
+
+#[test]
+fn test_inverse() {
+    let q = Quaternion::euler(Degrees(10.0), Degrees(20.0), Degrees(30.0));
+
+    assert_rotation_near(q * q.inverse(), Quaternion::identity());
+}
+
+#[test]
+fn test_look_rotation() {
+    let forward = Vector3::new(1.0, 0.0, 1.0);
+    let q = Quaternion::look_rotation(forward, Vector3::new(0.0, 1.0, 0.0));
+
+    assert_approx_eq!(q * Vector3::new(0.0, 0.0, 1.0), forward.normalized());
+    assert_approx_eq!(q * Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_matrix_conversion() {
+    let q = Quaternion::euler(Degrees(-70.0), Degrees(120.0), Degrees(15.0));
+    let m: Matrix4 = q.into();
+
+    assert_rotation_near(Quaternion::from_matrix(m), q);
+}
+
+#[test]
+fn test_slerp() {
+    let a = Quaternion::identity();
+    let b = Quaternion::axis_angle(Vector3::new(0.0, 1.0, 0.0), Degrees(90.0));
+
+    assert_rotation_near(Quaternion::slerp(a, b, 0.5), Quaternion::axis_angle(Vector3::new(0.0, 1.0, 0.0), Degrees(45.0)));
+    assert_rotation_near(Quaternion::nlerp(a, b, 1.0), b);
+    assert_relative_eq!(Quaternion::angle(a, Quaternion::slerp(a, b, 0.25)), Degrees(22.5), 0.001);
+}
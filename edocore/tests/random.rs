@@ -0,0 +1,135 @@
+use edocore::assert_approx_eq;
+use edocore::math::geometry::Aabb;
+use edocore::math::random::Random;
+use edocore::math::vector::{Vector2, Vector3};
+
+#[test]
+fn test_reference_sequence() {
+    // Output of the PCG32 reference implementation seeded with 42 on stream 54
+    let mut random = Random::with_stream(42, 54);
+    let expected = [0xa15c_02b7, 0x7b47_f409, 0xba1d_3330, 0x83d2_f293, 0xbfa4_784b, 0xcbed_606e];
+    for &value in expected.iter() {
+        assert_eq!(random.next_u32(), value);
+    }
+}
+
+#[test]
+fn test_streams() {
+    let first = (0..8).map(|_| Random::with_stream(7, 1).next_u32()).collect::<Vec<_>>();
+    let mut a = Random::with_stream(7, 1);
+    let mut b = Random::with_stream(7, 2);
+
+    assert!(first.iter().all(|&v| v == first[0]));
+    assert_ne!((0..4).map(|_| a.next_u32()).collect::<Vec<_>>(), (0..4).map(|_| b.next_u32()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_split() {
+    let mut parent = Random::new(3);
+    let mut copy = parent.clone();
+    let mut child = parent.split();
+    let mut child_copy = copy.split();
+
+    assert_eq!(parent, copy);
+    assert_eq!(child.next_u64(), child_copy.next_u64());
+    assert_ne!(child.next_u32(), parent.next_u32());
+}
+
+#[test]
+fn test_ranges() {
+    let mut random = Random::new(11);
+    let mut seen = [false; 6];
+
+    for _ in 0..1000 {
+        let f = random.next_f32();
+        assert!((0.0..1.0).contains(&f));
+        assert!((0.0..1.0).contains(&random.next_f64()));
+        assert!((-2.0..3.0).contains(&random.range_f32(-2.0, 3.0)));
+
+        let i = random.range_i32(-3, 3);
+        assert!((-3..3).contains(&i));
+        seen[(i + 3) as usize] = true;
+    }
+
+    assert!(seen.iter().all(|&s| s));
+    assert_eq!(random.range_i32(i32::MIN, i32::MIN + 1), i32::MIN);
+
+    // Only min and max are representable here, so the product often rounds up to max
+    let (min, max) = (16_777_216.0, 16_777_218.0);
+    assert!((0..100).all(|_| random.range_f32(min, max) == min));
+}
+
+#[test]
+#[should_panic]
+fn test_empty_range() {
+    Random::new(0).range_i32(4, 4);
+}
+
+#[test]
+fn test_circle_and_sphere() {
+    let mut random = Random::new(5);
+
+    for _ in 0..1000 {
+        assert!(random.inside_unit_circle().magnitude() <= 1.0);
+        assert!(random.inside_unit_sphere().magnitude() <= 1.0);
+        assert_approx_eq!(random.on_unit_circle().magnitude(), 1.0);
+        assert_approx_eq!(random.on_unit_sphere().magnitude(), 1.0);
+    }
+}
+
+#[test]
+fn test_hemispheres() {
+    let mut random = Random::new(8);
+    let normal = Vector3::new(1.0, 2.0, -1.0).normalized();
+    let mut average = 0.0;
+
+    for _ in 0..4000 {
+        let direction = random.on_hemisphere(normal);
+        assert_approx_eq!(direction.magnitude(), 1.0);
+        assert!(Vector3::dot(direction, normal) >= 0.0);
+
+        let direction = random.cosine_hemisphere(normal);
+        assert_approx_eq!(direction.magnitude(), 1.0, 0.0001);
+        assert!(Vector3::dot(direction, normal) >= 0.0);
+        average += Vector3::dot(direction, normal) / 4000.0;
+    }
+
+    // The cosine of the angle to the normal averages to 2/3 for cosine weighting
+    assert_approx_eq!(average, 2.0 / 3.0, 0.02);
+}
+
+#[test]
+fn test_inside_aabb() {
+    let mut random = Random::new(2);
+    let aabb = Aabb::new(Vector3::new(-1.0, 2.0, 0.0), Vector3::new(3.0, 4.0, 0.5));
+
+    for _ in 0..1000 {
+        assert!(aabb.contains_point(random.inside_aabb(aabb)));
+    }
+}
+
+#[test]
+fn test_rotation() {
+    let mut random = Random::new(13);
+
+    for _ in 0..1000 {
+        assert_approx_eq!(random.rotation().magnitude(), 1.0, 0.0001);
+    }
+}
+
+#[test]
+fn test_poisson_disk() {
+    let min = Vector2::new(-5.0, 0.0);
+    let max = Vector2::new(5.0, 8.0);
+    let points = Random::new(21).poisson_disk(min, max, 0.5);
+
+    // Densely packed, but no two points closer than the radius
+    assert!(points.len() > 150);
+    for (i, &a) in points.iter().enumerate() {
+        assert!(a.x >= min.x && a.y >= min.y && a.x <= max.x && a.y <= max.y);
+        assert!(points[i + 1..].iter().all(|&b| (a - b).magnitude() >= 0.5));
+    }
+
+    assert_eq!(points, Random::new(21).poisson_disk(min, max, 0.5));
+    assert!(Random::new(21).poisson_disk(min, max, 0.0).is_empty());
+}
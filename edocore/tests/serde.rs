@@ -0,0 +1,90 @@
+#![cfg(feature = "serde")]
+
+use edocore::math::curve::{ArcLengthTable, BSpline, CatmullRom, CubicBezier};
+use edocore::math::geometry::{Aabb, Sphere};
+use edocore::math::matrix::{Matrix2, Matrix4};
+use edocore::math::quaternion::Quaternion;
+use edocore::math::transform::Transform;
+use edocore::math::vector::{IVector2, UVector3, Vector2, Vector3};
+
+#[test]
+fn test_vector() {
+    let v = Vector3::new(1.0, 2.5, -3.0);
+    let json = serde_json::to_string(&v).unwrap();
+
+    assert_eq!(json, "[1.0,2.5,-3.0]");
+    assert_eq!(serde_json::from_str::<Vector3>(&json).unwrap(), v);
+    assert_eq!(serde_json::from_str::<UVector3>("[1,2,3]").unwrap(), UVector3::new(1, 2, 3));
+    assert_eq!(serde_json::to_string(&IVector2::new(-1, 4)).unwrap(), "[-1,4]");
+}
+
+#[test]
+fn test_wrong_length() {
+    assert!(serde_json::from_str::<Vector3>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<Vector3>("[1.0,2.0,3.0,4.0]").is_err());
+    assert!(serde_json::from_str::<Matrix2>("[[1.0,2.0],[3.0]]").is_err());
+
+    let error = serde_json::from_str::<Vector3>("[1.0]").unwrap_err().to_string();
+    assert!(error.contains("invalid length 1"), "{}", error);
+}
+
+#[test]
+fn test_matrix() {
+    let m = Matrix2::new([1.0, 2.0].into(), [3.0, 4.0].into());
+    let json = serde_json::to_string(&m).unwrap();
+
+    assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+    assert_eq!(serde_json::from_str::<Matrix2>(&json).unwrap(), m);
+
+    let identity = Matrix4::identity();
+    assert_eq!(serde_json::from_str::<Matrix4>(&serde_json::to_string(&identity).unwrap()).unwrap(), identity);
+}
+
+#[test]
+fn test_quaternion() {
+    let q = Quaternion::new(0.0, 0.5, 0.5, 1.0);
+    let json = serde_json::to_string(&q).unwrap();
+
+    assert_eq!(json, "[0.0,0.5,0.5,1.0]");
+    assert_eq!(serde_json::from_str::<Quaternion>(&json).unwrap(), q);
+}
+
+#[test]
+fn test_shapes() {
+    let t = Transform::new(Vector3::new(1.0, 2.0, 3.0), Quaternion::identity(), Vector3::new(1.0, 1.0, 1.0));
+    let json = serde_json::to_string(&t).unwrap();
+
+    assert_eq!(json, r#"{"translation":[1.0,2.0,3.0],"rotation":[0.0,0.0,0.0,1.0],"scale":[1.0,1.0,1.0]}"#);
+    assert_eq!(serde_json::from_str::<Transform>(&json).unwrap(), t);
+
+    let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(serde_json::from_str::<Aabb>(&serde_json::to_string(&aabb).unwrap()).unwrap(), aabb);
+
+    let sphere = Sphere::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    assert_eq!(serde_json::to_string(&sphere).unwrap(), r#"{"center":[0.0,1.0,0.0],"radius":2.0}"#);
+}
+
+#[test]
+fn test_curves() {
+    let bezier = CubicBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(3.0, 2.0), Vector2::new(4.0, 0.0));
+    let json = serde_json::to_string(&bezier).unwrap();
+    assert_eq!(json, r#"{"p0":[0.0,0.0],"p1":[1.0,2.0],"p2":[3.0,2.0],"p3":[4.0,0.0]}"#);
+    assert_eq!(serde_json::from_str::<CubicBezier<f32, 2>>(&json).unwrap(), bezier);
+
+    let points = vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0), Vector2::new(3.0, 1.0)];
+    let spline = CatmullRom::new(points.clone());
+    let json = serde_json::to_string(&spline).unwrap();
+    assert_eq!(serde_json::from_str::<CatmullRom<f32, 2>>(&json).unwrap(), spline);
+    assert_eq!(serde_json::from_str::<BSpline<f32, 2>>(&json).unwrap(), BSpline::new(points));
+
+    let table = ArcLengthTable::new(&bezier, 8);
+    let read = serde_json::from_str::<ArcLengthTable<f32>>(&serde_json::to_string(&table).unwrap()).unwrap();
+    assert_eq!(read.parameter(2.0), table.parameter(2.0));
+}
+
+#[test]
+fn test_too_few_curve_points() {
+    assert!(serde_json::from_str::<CatmullRom<f32, 2>>(r#"{"points":[[0.0,0.0]]}"#).is_err());
+    assert!(serde_json::from_str::<BSpline<f32, 2>>(r#"{"points":[[0.0,0.0],[1.0,0.0],[2.0,0.0]]}"#).is_err());
+    assert!(serde_json::from_str::<ArcLengthTable<f32>>(r#"{"lengths":[]}"#).is_err());
+}
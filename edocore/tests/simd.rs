@@ -0,0 +1,70 @@
+// Checks that Vector4 and Matrix4 produce the same bits as a plain scalar
+// implementation, whether or not the "simd" feature is enabled.
+use edocore::math::matrix::Matrix4;
+use edocore::math::vector::Vector4;
+
+/// Small deterministic generator so every run checks the same values
+struct Lcg(u32);
+
+impl Lcg {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1664525).wrapping_add(1013904223);
+        (self.0 >> 8) as f32 / (1 << 24) as f32 * 200.0 - 100.0
+    }
+
+    fn vector(&mut self) -> Vector4 {
+        Vector4::new(self.next(), self.next(), self.next(), self.next())
+    }
+
+    fn matrix(&mut self) -> Matrix4 {
+        Matrix4::new(self.vector(), self.vector(), self.vector(), self.vector())
+    }
+}
+
+fn dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+    let mut sum = 0.0;
+    for i in 0..4 {
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+#[test]
+fn test_vector4_ops() {
+    let mut rng = Lcg(7);
+    for _ in 0..1000 {
+        let a = rng.vector();
+        let b = rng.vector();
+        let s = rng.next();
+
+        for i in 0..4 {
+            assert_eq!((a + b).values[i].to_bits(), (a.values[i] + b.values[i]).to_bits());
+            assert_eq!((a - b).values[i].to_bits(), (a.values[i] - b.values[i]).to_bits());
+            assert_eq!((a * b).values[i].to_bits(), (a.values[i] * b.values[i]).to_bits());
+            assert_eq!((a / b).values[i].to_bits(), (a.values[i] / b.values[i]).to_bits());
+            assert_eq!((a * s).values[i].to_bits(), (a.values[i] * s).to_bits());
+            assert_eq!((a / s).values[i].to_bits(), (a.values[i] / s).to_bits());
+        }
+        assert_eq!(Vector4::dot(a, b).to_bits(), dot(a.values, b.values).to_bits());
+    }
+}
+
+#[test]
+fn test_matrix4_mul() {
+    let mut rng = Lcg(11);
+    for _ in 0..1000 {
+        let a = rng.matrix();
+        let b = rng.matrix();
+        let v = rng.vector();
+        let product = a * b;
+        let transformed = a * v;
+
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = dot(a.values[row], b.get_column(col).values);
+                assert_eq!(product.values[row][col].to_bits(), expected.to_bits());
+            }
+            assert_eq!(transformed.values[row].to_bits(), dot(a.values[row], v.values).to_bits());
+        }
+    }
+}
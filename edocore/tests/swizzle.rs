@@ -0,0 +1,44 @@
+use edocore::math::vector::{IVector2, IVector3, IVector4, UVector4, Vector2, Vector3, Vector4};
+
+#[test]
+fn test_vector2_swizzles() {
+    let v = Vector2::new(1.0, 2.0);
+
+    assert_eq!(v.yx(), Vector2::new(2.0, 1.0));
+    assert_eq!(v.xxy(), Vector3::new(1.0, 1.0, 2.0));
+    assert_eq!(v.yyxy(), Vector4::new(2.0, 2.0, 1.0, 2.0));
+    assert_eq!(v.gr(), v.yx());
+}
+
+#[test]
+fn test_vector3_swizzles() {
+    let v = IVector3::new(1, 2, 3);
+
+    assert_eq!(v.xz(), IVector2::new(1, 3));
+    assert_eq!(v.zyx(), IVector3::new(3, 2, 1));
+    assert_eq!(v.xyzz(), IVector4::new(1, 2, 3, 3));
+    assert_eq!(v.bgr(), v.zyx());
+}
+
+#[test]
+fn test_vector4_swizzles() {
+    let color = Vector4::new(0.1, 0.2, 0.3, 0.4);
+    let v = UVector4::new(1, 2, 3, 4);
+
+    assert_eq!(color.rgb(), Vector3::new(0.1, 0.2, 0.3));
+    assert_eq!(color.ar(), Vector2::new(0.4, 0.1));
+    assert_eq!(color.abgr(), Vector4::new(0.4, 0.3, 0.2, 0.1));
+    assert_eq!(v.wzyx(), UVector4::new(4, 3, 2, 1));
+    assert_eq!(v.xyzw(), v);
+    assert_eq!(v.wwww(), UVector4::new(4, 4, 4, 4));
+}
+
+#[test]
+fn test_extend_truncate() {
+    let v = Vector2::new(1.0, 2.0);
+
+    assert_eq!(v.extend(3.0), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.extend(3.0).extend(4.0), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0).truncate(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(IVector3::new(5, 6, 7).truncate(), IVector2::new(5, 6));
+}
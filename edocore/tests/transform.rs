@@ -0,0 +1,66 @@
+use edocore::assert_approx_eq;
+use edocore::math::angle::Degrees;
+use edocore::math::matrix::Matrix4;
+use edocore::math::quaternion::Quaternion;
+use edocore::math::transform::Transform;
+use edocore::math::vector::Vector3;
+
+fn sample() -> Transform {
+    Transform::new(Vector3::new(1.0, 2.0, 3.0), Quaternion::euler(Degrees(0.0), Degrees(90.0), Degrees(0.0)), Vector3::new(2.0, 2.0, 2.0))
+}
+
+#[test]
+fn test_transform_point() {
+    let t = sample();
+
+    assert_approx_eq!(t.transform_point(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(3.0, 2.0, 3.0));
+    assert_approx_eq!(t.transform_direction(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(2.0, 0.0, 0.0));
+    assert_approx_eq!(t.inverse_transform_point(Vector3::new(3.0, 2.0, 3.0)), Vector3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_matrix() {
+    let mut t = sample();
+    let p = Vector3::new(4.0, -1.0, 0.5);
+
+    assert_approx_eq!(t.matrix().transform_point(p), t.transform_point(p));
+
+    t.set_translation(Vector3::new(0.0, 0.0, 0.0));
+    assert_approx_eq!(Matrix4::from(&t).transform_point(p), t.transform_point(p));
+}
+
+#[test]
+fn test_combine() {
+    let parent = sample();
+    let child = Transform::new(Vector3::new(0.0, 1.0, 0.0), Quaternion::euler(Degrees(45.0), Degrees(0.0), Degrees(0.0)), Vector3::new(1.0, 3.0, 1.0));
+    let world = &parent * &child;
+    let p = Vector3::new(1.0, 1.0, 1.0);
+
+    assert_approx_eq!(world.transform_point(p), parent.transform_point(child.transform_point(p)));
+}
+
+#[test]
+fn test_inverse() {
+    let t = sample();
+    let p = Vector3::new(-2.0, 5.0, 1.0);
+
+    assert_approx_eq!(t.inverse().transform_point(t.transform_point(p)), p);
+    assert_approx_eq!(Transform::combine(&t, &t.inverse()).translation(), Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_inverse_zero_scale() {
+    let t = Transform::new(Vector3::new(1.0, 0.0, 0.0), Quaternion::identity(), Vector3::new(2.0, 0.0, 1.0));
+
+    assert_eq!(t.inverse_transform_direction(Vector3::new(4.0, 3.0, 1.0)), Vector3::new(2.0, 0.0, 1.0));
+    assert_eq!(t.inverse_transform_point(Vector3::new(5.0, 3.0, 1.0)), Vector3::new(2.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_copy_and_sync() {
+    fn assert_copy_sync<T: Copy + Send + Sync>(_: T) {}
+
+    let t = sample();
+    assert_copy_sync(t);
+    assert_eq!(t, sample());
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use edocore::math::fixed::Fixed;
+use edocore::math::scalar::Float;
+use edocore::math::vector::{FixedVector2, IVector2, IVector3, UVector3, Vector2, Vector3, Vector4};
+
+#[test]
+fn test_component_wise_operators() {
+    let a = Vector3::new(2.0, 4.0, 6.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(a * b, Vector3::new(2.0, 8.0, 18.0));
+    assert_eq!(a / b, Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(a + 1.0, Vector3::new(3.0, 5.0, 7.0));
+    assert_eq!(a - 1.0, Vector3::new(1.0, 3.0, 5.0));
+    assert_eq!(IVector2::new(7, -7) / IVector2::new(2, 2), IVector2::new(3, -3));
+}
+
+#[test]
+fn test_compound_assignment() {
+    let mut v = Vector3::new(1.0, 2.0, 3.0);
+    v += Vector3::new(1.0, 1.0, 1.0);
+    v *= 2.0;
+    v -= 1.0;
+    v /= Vector3::new(1.0, 5.0, 7.0);
+    assert_eq!(v, Vector3::new(3.0, 1.0, 1.0));
+
+    let mut i = UVector3::new(1, 2, 3);
+    i *= UVector3::new(2, 2, 2);
+    i += 1;
+    assert_eq!(i, UVector3::new(3, 5, 7));
+}
+
+#[test]
+fn test_index() {
+    let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    v[2] = 7.0;
+
+    assert_eq!(v[0], 1.0);
+    assert_eq!(v[2], 7.0);
+    assert_eq!(v.z, 7.0);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let v = IVector2::new(1, 2);
+    let _ = v[2];
+}
+
+#[test]
+fn test_default_and_hash() {
+    assert_eq!(Vector3::default(), Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(IVector3::default(), IVector3::new(0, 0, 0));
+    assert_eq!(format!("{:?}", IVector2::new(1, 2)), "Vector { values: [1, 2] }");
+
+    let set = [IVector3::new(1, 2, 3), IVector3::new(1, 2, 3), IVector3::new(3, 2, 1)].iter().copied().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_min_max_abs() {
+    let a = Vector3::new(1.0, -5.0, 3.0);
+    let b = Vector3::new(2.0, -6.0, 0.0);
+
+    assert_eq!(Vector3::min(a, b), Vector3::new(1.0, -6.0, 0.0));
+    assert_eq!(Vector3::max(a, b), Vector3::new(2.0, -5.0, 3.0));
+    assert_eq!(a.abs(), Vector3::new(1.0, 5.0, 3.0));
+    assert_eq!(IVector3::new(-1, 0, 4).abs(), IVector3::new(1, 0, 4));
+    assert_eq!(UVector3::max(UVector3::new(1, 9, 3), UVector3::new(4, 2, 3)), UVector3::new(4, 9, 3));
+}
+
+#[test]
+fn test_rounding() {
+    let v = Vector4::new(1.5, -1.5, 2.2, -2.7);
+
+    assert_eq!(v.floor(), Vector4::new(1.0, -2.0, 2.0, -3.0));
+    assert_eq!(v.ceil(), Vector4::new(2.0, -1.0, 3.0, -2.0));
+    assert_eq!(v.round(), Vector4::new(2.0, -2.0, 2.0, -3.0));
+
+    let f = FixedVector2::from_f32(Vector2::new(1.5, -1.25));
+    assert_eq!(f.floor().to_f32(), Vector2::new(1.0, -2.0));
+    assert_eq!(f.ceil().to_f32(), Vector2::new(2.0, -1.0));
+    assert_eq!(f.round().to_f32(), Vector2::new(2.0, -1.0));
+    assert_eq!(Fixed::from_f32(-2.5).round(), Fixed::from_i32(-3));
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(Vector3::from(IVector3::new(1, -2, 3)), Vector3::new(1.0, -2.0, 3.0));
+    assert_eq!(Vector3::from(UVector3::new(1, 2, 3)), Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(UVector3::try_from(IVector3::new(1, 2, 3)), Ok(UVector3::new(1, 2, 3)));
+    assert!(UVector3::try_from(IVector3::new(1, -2, 3)).is_err());
+    assert_eq!(IVector3::try_from(UVector3::new(1, 2, 3)), Ok(IVector3::new(1, 2, 3)));
+    assert!(IVector3::try_from(UVector3::new(1, u32::MAX, 3)).is_err());
+
+    assert_eq!(IVector3::try_from(Vector3::new(1.9, -1.9, 0.0)), Ok(IVector3::new(1, -1, 0)));
+    assert_eq!(IVector3::try_from(Vector3::new(-2_147_483_648.0, 0.0, 0.0)), Ok(IVector3::new(i32::MIN, 0, 0)));
+    assert!(IVector3::try_from(Vector3::new(2_147_483_648.0, 0.0, 0.0)).is_err());
+    assert!(IVector3::try_from(Vector3::new(f32::NAN, 0.0, 0.0)).is_err());
+    assert_eq!(UVector3::try_from(Vector3::new(-0.5, 0.0, 1.0)), Ok(UVector3::new(0, 0, 1)));
+    assert!(UVector3::try_from(Vector3::new(-1.0, 0.0, 0.0)).is_err());
+}
+
+#[test]
+fn test_arrays_and_tuples() {
+    let v = IVector3::from((1, 2, 3));
+
+    assert_eq!(v, IVector3::new(1, 2, 3));
+    assert_eq!(<[i32; 3]>::from(v), [1, 2, 3]);
+    assert_eq!(<(i32, i32, i32)>::from(v), (1, 2, 3));
+    assert_eq!(Vector2::from((1.0, 2.0)), Vector2::new(1.0, 2.0));
+    assert_eq!(<(f32, f32, f32, f32)>::from(Vector4::from((1.0, 2.0, 3.0, 4.0))), (1.0, 2.0, 3.0, 4.0));
+}
@@ -1,17 +1,26 @@
-﻿use edocore::math::vector::Vector2;
+﻿use edocore::{assert_approx_eq, assert_relative_eq};
+use edocore::math::angle::Degrees;
+use edocore::math::vector::Vector2;
 
 #[test]
 fn test_constructor() {
-    let a = Vector2 { x: 2.0, y: 3.0 };
+    let a = Vector2::from([2.0, 3.0]);
     let b = Vector2::new(2.0, 3.0);
 
     assert_eq!(a.x, b.x);
     assert_eq!(a.y, b.y)
 }
 
-fn test_angle() {}
+#[test]
+fn test_angle() {
+    assert_relative_eq!(Vector2::angle(Vector2::new(1.0, 0.0), Vector2::new(0.0, 3.0)), Degrees(90.0));
+    assert_relative_eq!(Vector2::angle(Vector2::new(2.0, 0.0), Vector2::new(-1.0, 0.0)), Degrees(180.0));
+}
 
-fn test_distance() {}
+#[test]
+fn test_distance() {
+    assert_approx_eq!(Vector2::distance(Vector2::new(1.0, 1.0), Vector2::new(4.0, 5.0)), 5.0);
+}
 
 #[test]
 fn test_dot() {
@@ -32,26 +41,70 @@ fn test_add() {
     assert_eq!(c.y, result.y);
 }
 
-fn test_lerp() {}
+#[test]
+fn test_lerp() {
+    let a = Vector2::new(0.0, 2.0);
+    let b = Vector2::new(4.0, 6.0);
+
+    assert_eq!(Vector2::lerp(a, b, 0.25), Vector2::new(1.0, 3.0));
+}
 
 #[test]
 fn test_magnitude() {
-    assert_eq!(Vector2::new(3.0, 4.0).magnitude(), 5.0);
+    assert_approx_eq!(Vector2::new(3.0, 4.0).magnitude(), 5.0);
+}
+
+#[test]
+fn test_move_toward() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(0.0, 10.0);
+
+    assert_eq!(Vector2::move_toward(a, b, 4.0), Vector2::new(0.0, 4.0));
+    assert_eq!(Vector2::move_toward(a, b, 12.0), b);
+}
+
+#[test]
+fn test_normalize() {
+    let mut a = Vector2::new(3.0, 4.0);
+    a.normalize();
+
+    assert_approx_eq!(a, Vector2::new(0.6, 0.8));
 }
 
-fn test_move_toward() {}
+#[test]
+fn test_normalized() {
+    assert_eq!(Vector2::new(0.0, 5.0).normalized(), Vector2::new(0.0, 1.0));
+    assert_eq!(Vector2::new(0.0, 0.0).normalized(), Vector2::new(0.0, 0.0));
+}
 
-fn test_normalize() {}
+#[test]
+fn test_perpendicular() {
+    assert_eq!(Vector2::perpendicular(Vector2::new(1.0, 2.0)), Vector2::new(-2.0, 1.0));
+}
 
-fn test_normalized() {}
+#[test]
+fn test_reflect() {
+    let dir = Vector2::new(1.0, -1.0);
+    let norm = Vector2::new(0.0, 1.0);
 
-fn test_perpendicular() {}
+    assert_eq!(Vector2::reflect(dir, norm), Vector2::new(1.0, 1.0));
+}
+
+#[test]
+fn test_scale() {
+    let mut a = Vector2::new(1.0, 2.0);
+    a.scale(Vector2::new(3.0, 4.0));
 
-fn test_reflect() {}
+    assert_eq!(a, Vector2::new(3.0, 8.0));
+}
 
-fn test_scale() {}
+#[test]
+fn test_signed_angle() {
+    let a = Vector2::new(1.0, 0.0);
 
-fn test_signed_angle() {}
+    assert_relative_eq!(Vector2::signed_angle(a, Vector2::new(0.0, 1.0)), Degrees(90.0));
+    assert_relative_eq!(Vector2::signed_angle(a, Vector2::new(0.0, -1.0)), Degrees(-90.0));
+}
 
 #[test]
 fn test_square_magnitude() {
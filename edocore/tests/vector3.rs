@@ -1,8 +1,9 @@
-﻿use edocore::math::vector::Vector3;
+﻿use edocore::assert_approx_eq;
+use edocore::math::vector::{IVector3, UVector3, Vector3};
 
 #[test]
 fn test_constructor() {
-    let a = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    let a = Vector3::from([1.0, 2.0, 3.0]);
     let b = Vector3::new(1.0, 2.0, 3.0);
 
     assert_eq!(a.x, b.x);
@@ -28,3 +29,35 @@ fn test_dot() {
 
     assert_eq!(Vector3::dot(a, b), 32.0);
 }
+
+#[test]
+fn test_magnitude() {
+    assert_approx_eq!(Vector3::new(2.0, 3.0, 6.0).magnitude(), 7.0);
+}
+
+#[test]
+fn test_project() {
+    let a = Vector3::new(2.0, 3.0, 4.0);
+    let b = Vector3::new(0.0, 2.0, 0.0);
+
+    assert_approx_eq!(Vector3::project(a, b), Vector3::new(0.0, 3.0, 0.0));
+}
+
+#[test]
+fn test_integer_vectors() {
+    let a = IVector3::new(1, -2, 3);
+    let b = UVector3::new(4, 5, 6);
+
+    assert_eq!(-a * 2, IVector3::new(-2, 4, -6));
+    assert_eq!(UVector3::dot(b, b), 77);
+    assert_eq!(format!("{}", a), "(1, -2, 3)");
+}
+
+#[test]
+fn test_component_access() {
+    let mut a = Vector3::new(1.0, 2.0, 3.0);
+    a.y = 5.0;
+
+    assert_eq!(a.values, [1.0, 5.0, 3.0]);
+    assert_eq!(a.z, 3.0);
+}
@@ -0,0 +1,81 @@
+use edocore::math::vector::{IVector3, UVector3, Vector3};
+use edocore::math::voxel::{
+    chunk_local_to_world, delinearize, linearize, morton_decode_u32, morton_decode_u64, morton_encode_u32,
+    morton_encode_u64, neighbours, voxel_at, world_to_chunk, world_to_chunk_local, world_to_local, Neighbourhood,
+};
+
+#[test]
+fn test_chunk_decomposition() {
+    let size = UVector3::new(16, 16, 16);
+
+    assert_eq!(world_to_chunk_local(IVector3::new(0, 15, 16), size), (IVector3::new(0, 0, 1), UVector3::new(0, 15, 0)));
+    assert_eq!(world_to_chunk(IVector3::new(-1, -16, -17), size), IVector3::new(-1, -1, -2));
+    assert_eq!(world_to_local(IVector3::new(-1, -16, -17), size), UVector3::new(15, 0, 15));
+
+    let size = UVector3::new(32, 8, 5);
+    for &world in [IVector3::new(-100, 37, -3), IVector3::new(31, -8, 4), IVector3::new(i32::MIN, 0, i32::MAX)].iter() {
+        let (chunk, local) = world_to_chunk_local(world, size);
+        assert!(local.x < size.x && local.y < size.y && local.z < size.z);
+        assert_eq!(chunk_local_to_world(chunk, local, size), world);
+    }
+}
+
+#[test]
+fn test_voxel_at() {
+    assert_eq!(voxel_at(Vector3::new(0.5, -0.5, 3.0)), IVector3::new(0, -1, 3));
+    assert_eq!(voxel_at(Vector3::new(-2.0, -1.9, 1.999)), IVector3::new(-2, -2, 1));
+}
+
+#[test]
+fn test_linearize() {
+    let size = UVector3::new(4, 3, 2);
+
+    assert_eq!(linearize(UVector3::new(1, 0, 0), size), 1);
+    assert_eq!(linearize(UVector3::new(0, 1, 0), size), 4);
+    assert_eq!(linearize(UVector3::new(0, 0, 1), size), 12);
+    for index in 0..24 {
+        assert_eq!(linearize(delinearize(index, size), size), index);
+    }
+    assert_eq!(delinearize(23, size), UVector3::new(3, 2, 1));
+}
+
+#[test]
+fn test_morton() {
+    assert_eq!(morton_encode_u32(UVector3::new(1, 0, 0)), 0b001);
+    assert_eq!(morton_encode_u32(UVector3::new(0, 1, 0)), 0b010);
+    assert_eq!(morton_encode_u32(UVector3::new(0, 0, 1)), 0b100);
+    assert_eq!(morton_encode_u32(UVector3::new(3, 1, 2)), 0b101_011);
+    assert_eq!(morton_encode_u32(UVector3::new(1023, 1023, 1023)), (1 << 30) - 1);
+    assert_eq!(morton_encode_u64(UVector3::new(0x1f_ffff, 0x1f_ffff, 0x1f_ffff)), (1 << 63) - 1);
+
+    for &position in [UVector3::new(0, 0, 0), UVector3::new(5, 1000, 513), UVector3::new(1023, 0, 77)].iter() {
+        assert_eq!(morton_decode_u32(morton_encode_u32(position)), position);
+    }
+    for &position in [UVector3::new(0x1f_ffff, 3, 0x10_0001), UVector3::new(123_456, 654_321, 1)].iter() {
+        assert_eq!(morton_decode_u64(morton_encode_u64(position)), position);
+        assert_eq!(morton_encode_u64(position) & 0x3fff_ffff, morton_encode_u32(position.map(|v| v & 0x3ff)) as u64);
+    }
+
+    // Bits above the supported width are dropped
+    assert_eq!(morton_encode_u32(UVector3::new(1024, 0, 0)), 0);
+}
+
+#[test]
+fn test_neighbours() {
+    let voxel = IVector3::new(5, -3, 0);
+
+    for &(neighbourhood, count, max_axes) in
+        [(Neighbourhood::Faces, 6, 1), (Neighbourhood::Edges, 18, 2), (Neighbourhood::Corners, 26, 3)].iter()
+    {
+        let found = neighbours(voxel, neighbourhood).collect::<Vec<_>>();
+        assert_eq!(found.len(), count);
+        for (i, &neighbour) in found.iter().enumerate() {
+            assert!(!found[i + 1..].contains(&neighbour));
+            let offset = neighbour - voxel;
+            let axes = offset.values.iter().filter(|&&v| v != 0).count();
+            assert!(axes >= 1 && axes <= max_axes && offset.values.iter().all(|v| v.abs() <= 1));
+        }
+    }
+
+    assert_eq!(Neighbourhood::Faces.offsets().next(), Some(IVector3::new(0, 0, -1)));
+}